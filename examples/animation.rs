@@ -104,7 +104,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             false,
             false,
         )?;
-        canvas.present();
+        canvas.present()?;
 
         std::thread::sleep(Duration::from_millis(100));
     }
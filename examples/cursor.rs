@@ -29,7 +29,7 @@ pub fn run(png: &Path) -> Result<(), Box<dyn std::error::Error>> {
     cursor.set();
 
     canvas.clear();
-    canvas.present();
+    canvas.present()?;
 
     canvas.set_draw_color(Color::RGBA(255, 255, 255, 255));
 
@@ -45,7 +45,7 @@ pub fn run(png: &Path) -> Result<(), Box<dyn std::error::Error>> {
                 } => break 'mainloop,
                 Event::MouseButtonDown { x, y, .. } => {
                     canvas.fill_rect(Rect::new(x, y, 1, 1))?;
-                    canvas.present();
+                    canvas.present()?;
                 }
                 _ => {}
             }
@@ -20,7 +20,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     canvas.set_draw_color(Color::RGB(255, 0, 0));
     canvas.clear();
-    canvas.present();
+    canvas.present()?;
     let mut event_pump = sdl_context.event_pump()?;
 
     'running: loop {
@@ -36,7 +36,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         canvas.clear();
-        canvas.present();
+        canvas.present()?;
         ::std::thread::sleep(Duration::new(0, 1_000_000_000u32 / 30));
         // The rest of the game loop goes here...
     }
@@ -439,7 +439,7 @@ fn draw(canvas: &mut Canvas<Window>, edges: &[[f32; 6]], players: &[Player], pla
     }
     canvas.set_clip_rect(None);
     canvas.set_draw_color(Color::RGB(255, 255, 255));
-    canvas.present();
+    canvas.present().unwrap();
 }
 
 fn init_players(players: &mut [Player], len: usize) {
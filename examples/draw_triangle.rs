@@ -91,7 +91,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     canvas.set_draw_color(Color::RGB(255, 0, 0));
     fill_triangle(&mut canvas, (100, 100), (200, 200), (300, 100));
 
-    canvas.present();
+    canvas.present()?;
 
     'running: loop {
         for event in event_pump.poll_iter() {
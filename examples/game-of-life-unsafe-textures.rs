@@ -242,7 +242,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     // However the canvas has not been updated to the window yet, everything has been processed to
     // an internal buffer, but if we want our buffer to be displayed on the window, we need to call
     // `present`. We need to call this everytime we want to render a new frame on the window.
-    canvas.present();
+    canvas.present()?;
 
     // Create a "target" texture so that we can use our Renderer with it later
     let (square_texture1, square_texture2) = dummy_texture(&mut canvas)?;
@@ -313,7 +313,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                 )?;
             }
         }
-        canvas.present();
+        canvas.present()?;
         if let game_of_life::State::Playing = game.state() {
             frame += 1;
         };
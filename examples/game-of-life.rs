@@ -232,7 +232,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     // However the canvas has not been updated to the window yet, everything has been processed to
     // an internal buffer, but if we want our buffer to be displayed on the window, we need to call
     // `present`. We need to call this everytime we want to render a new frame on the window.
-    canvas.present();
+    canvas.present()?;
 
     // this struct manages textures. For lifetime reasons, the canvas cannot directly create
     // textures, you have to create a `TextureCreator` instead.
@@ -307,7 +307,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                 )?;
             }
         }
-        canvas.present();
+        canvas.present()?;
         if let game_of_life::State::Playing = game.state() {
             frame += 1;
         };
@@ -27,7 +27,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     canvas.set_draw_color(pixels::Color::RGB(0, 0, 0));
     canvas.clear();
-    canvas.present();
+    canvas.present()?;
 
     let mut lastx = 0;
     let mut lasty = 0;
@@ -50,7 +50,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         for i in 0..400 {
                             canvas.pixel(i as i16, i as i16, 0xFF000FFu32)?;
                         }
-                        canvas.present();
+                        canvas.present()?;
                     }
                 }
 
@@ -60,7 +60,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     lastx = x as i16;
                     lasty = y as i16;
                     println!("mouse btn down at ({},{})", x, y);
-                    canvas.present();
+                    canvas.present()?;
                 }
 
                 _ => {}
@@ -20,7 +20,7 @@ pub fn run(png: &Path) -> Result<(), Box<dyn std::error::Error>> {
     let texture = texture_creator.load_texture(png)?;
 
     canvas.copy(&texture, None, None)?;
-    canvas.present();
+    canvas.present()?;
 
     'mainloop: loop {
         for event in sdl_context.event_pump()?.poll_iter() {
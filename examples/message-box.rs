@@ -20,7 +20,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     canvas.set_draw_color(Color::RGB(255, 0, 0));
     canvas.clear();
-    canvas.present();
+    canvas.present()?;
     let mut event_pump = sdl_context.event_pump()?;
 
     'running: loop {
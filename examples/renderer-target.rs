@@ -59,7 +59,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             false,
             false,
         )?;
-        canvas.present();
+        canvas.present()?;
     }
 
     Ok(())
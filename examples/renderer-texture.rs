@@ -50,7 +50,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
         false,
         false,
     )?;
-    canvas.present();
+    canvas.present()?;
 
     let mut event_pump = sdl_context.event_pump()?;
 
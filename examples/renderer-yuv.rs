@@ -58,7 +58,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     canvas.clear();
     canvas.copy(&texture, None, Some(FRect::new(100.0, 100.0, 256.0, 256.0)))?;
-    canvas.present();
+    canvas.present()?;
 
     let mut event_pump = sdl_context.event_pump()?;
 
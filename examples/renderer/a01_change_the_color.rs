@@ -51,7 +51,7 @@ fn main() -> Result<(), Error> {
         canvas.clear();
 
         // Present the rendered frame
-        canvas.present();
+        canvas.present()?;
     }
 
     Ok(())
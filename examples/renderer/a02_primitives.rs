@@ -82,6 +82,6 @@ fn main() -> Result<(), Error> {
         )?;
 
         // Present the rendered frame
-        canvas.present();
+        canvas.present()?;
     }
 }
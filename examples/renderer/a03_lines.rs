@@ -97,7 +97,7 @@ impl AppState {
         }
 
         // Present the rendered frame
-        self.canvas.present();
+        self.canvas.present().unwrap();
     }
 }
 
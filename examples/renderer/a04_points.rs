@@ -100,7 +100,7 @@ impl AppState {
         self.canvas
             .draw_points(self.points.as_ref()) // Convert array to slice
             .unwrap(); // Draw all points
-        self.canvas.present();
+        self.canvas.present().unwrap();
     }
 }
 
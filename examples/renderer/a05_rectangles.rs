@@ -102,7 +102,7 @@ impl AppState {
         }
 
         // Present the updated canvas
-        self.canvas.present();
+        self.canvas.present().unwrap();
     }
 
     fn convert_frect_to_rect(frect: &FRect) -> Rect {
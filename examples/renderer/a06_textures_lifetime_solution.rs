@@ -103,7 +103,7 @@ impl AppState {
             .unwrap();
 
         // Present the updated canvas
-        self.canvas.present();
+        self.canvas.present().unwrap();
     }
 }
 
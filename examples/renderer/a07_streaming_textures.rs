@@ -102,7 +102,7 @@ impl AppState {
         self.canvas
             .copy(&self.texture, None, Some(dst_rect))
             .unwrap();
-        self.canvas.present();
+        self.canvas.present().unwrap();
     }
 }
 
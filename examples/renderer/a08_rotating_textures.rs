@@ -98,7 +98,7 @@ impl AppState {
         );
         // .unwrap();
 
-        self.canvas.present();
+        self.canvas.present().unwrap();
     }
 }
 
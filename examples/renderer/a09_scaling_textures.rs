@@ -88,7 +88,7 @@ impl AppState {
             .unwrap();
 
         // Present the updated canvas
-        self.canvas.present();
+        self.canvas.present().unwrap();
     }
 }
 
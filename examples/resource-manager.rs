@@ -73,7 +73,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             canvas.clear();
             canvas.copy(&texture, None, None)?;
             canvas.copy(&font_texture, None, None)?;
-            canvas.present();
+            canvas.present()?;
         }
     }
 
@@ -162,7 +162,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             draw_line(&mut canvas, p1, p2, Color::RGB(255, 255, 255));
         }
 
-        canvas.present();
+        canvas.present()?;
 
         angle_x += 0.02;
         angle_y += 0.03;
@@ -86,7 +86,7 @@ fn run(font_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     );
 
     canvas.copy(&texture, None, Some(target))?;
-    canvas.present();
+    canvas.present()?;
 
     'mainloop: loop {
         for event in sdl_context.event_pump()?.poll_iter() {
@@ -352,6 +352,7 @@ pub enum EventType {
 
     RenderTargetsReset = sys::events::SDL_EVENT_RENDER_TARGETS_RESET.0,
     RenderDeviceReset = sys::events::SDL_EVENT_RENDER_DEVICE_RESET.0,
+    RenderDeviceLost = sys::events::SDL_EVENT_RENDER_DEVICE_LOST.0,
 
     User = sys::events::SDL_EVENT_USER.0,
     Last = sys::events::SDL_EVENT_LAST.0,
@@ -453,6 +454,7 @@ impl TryFrom<u32> for EventType {
 
             SDL_EVENT_RENDER_TARGETS_RESET => RenderTargetsReset,
             SDL_EVENT_RENDER_DEVICE_RESET => RenderDeviceReset,
+            SDL_EVENT_RENDER_DEVICE_LOST => RenderDeviceLost,
 
             SDL_EVENT_USER => User,
             SDL_EVENT_LAST => Last,
@@ -880,6 +882,7 @@ pub enum Event {
 
     FingerDown {
         timestamp: u64,
+        window_id: u32,
         touch_id: u64,
         finger_id: u64,
         x: f32,
@@ -890,6 +893,7 @@ pub enum Event {
     },
     FingerUp {
         timestamp: u64,
+        window_id: u32,
         touch_id: u64,
         finger_id: u64,
         x: f32,
@@ -900,6 +904,7 @@ pub enum Event {
     },
     FingerMotion {
         timestamp: u64,
+        window_id: u32,
         touch_id: u64,
         finger_id: u64,
         x: f32,
@@ -969,6 +974,15 @@ pub enum Event {
     RenderDeviceReset {
         timestamp: u64,
     },
+    /// The render device has been lost and can't be recovered.
+    ///
+    /// The `Canvas` of the window `window_id` and its `Texture`s must be dropped and
+    /// recreated: any further use of the old renderer will fail with an SDL error.
+    RenderDeviceLost {
+        timestamp: u64,
+        /// The window of the renderer that lost its device.
+        window_id: u32,
+    },
 
     User {
         timestamp: u64,
@@ -1005,7 +1019,7 @@ unsafe impl Sync for Event {}
 // This would honestly be nice if it took &self instead of self,
 // but Event::User's raw pointers kind of removes that possibility.
 impl Event {
-    fn to_ll(&self) -> Option<sys::events::SDL_Event> {
+    pub(crate) fn to_ll(&self) -> Option<sys::events::SDL_Event> {
         let mut ret = mem::MaybeUninit::uninit();
         match *self {
             Event::User {
@@ -1555,10 +1569,68 @@ impl Event {
                 }
             }
 
-            Event::FingerDown { .. }
-            | Event::FingerUp { .. }
-            | Event::FingerMotion { .. }
-            | Event::DollarRecord { .. }
+            Event::FingerDown {
+                timestamp,
+                window_id,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                dx,
+                dy,
+                pressure,
+            }
+            | Event::FingerUp {
+                timestamp,
+                window_id,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                dx,
+                dy,
+                pressure,
+            }
+            | Event::FingerMotion {
+                timestamp,
+                window_id,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                dx,
+                dy,
+                pressure,
+            } => {
+                let r#type = match *self {
+                    Event::FingerDown { .. } => sys::events::SDL_EVENT_FINGER_DOWN,
+                    Event::FingerUp { .. } => sys::events::SDL_EVENT_FINGER_UP,
+                    _ => sys::events::SDL_EVENT_FINGER_MOTION,
+                };
+                let event = sys::events::SDL_TouchFingerEvent {
+                    r#type,
+                    reserved: 0,
+                    timestamp,
+                    touchID: touch_id,
+                    fingerID: finger_id,
+                    x,
+                    y,
+                    dx,
+                    dy,
+                    pressure,
+                    windowID: window_id,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_TouchFingerEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::DollarRecord { .. }
             | Event::MultiGesture { .. }
             | Event::ClipboardUpdate { .. }
             | Event::DropFile { .. }
@@ -1938,6 +2010,7 @@ impl Event {
                     let event = raw.tfinger;
                     Event::FingerDown {
                         timestamp: event.timestamp,
+                        window_id: event.windowID,
                         touch_id: event.touchID,
                         finger_id: event.fingerID,
                         x: event.x,
@@ -1951,6 +2024,7 @@ impl Event {
                     let event = raw.tfinger;
                     Event::FingerUp {
                         timestamp: event.timestamp,
+                        window_id: event.windowID,
                         touch_id: event.touchID,
                         finger_id: event.fingerID,
                         x: event.x,
@@ -1964,6 +2038,7 @@ impl Event {
                     let event = raw.tfinger;
                     Event::FingerMotion {
                         timestamp: event.timestamp,
+                        window_id: event.windowID,
                         touch_id: event.touchID,
                         finger_id: event.fingerID,
                         x: event.x,
@@ -2045,6 +2120,13 @@ impl Event {
                 EventType::RenderDeviceReset => Event::RenderDeviceReset {
                     timestamp: raw.common.timestamp,
                 },
+                EventType::RenderDeviceLost => {
+                    let event = raw.render;
+                    Event::RenderDeviceLost {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                    }
+                }
 
                 EventType::First => panic!("Unused event, EventType::First, was encountered"),
                 EventType::Last => panic!("Unusable event, EventType::Last, was encountered"),
@@ -2175,6 +2257,7 @@ impl Event {
             | (Self::AudioDeviceRemoved { .. }, Self::AudioDeviceRemoved { .. })
             | (Self::RenderTargetsReset { .. }, Self::RenderTargetsReset { .. })
             | (Self::RenderDeviceReset { .. }, Self::RenderDeviceReset { .. })
+            | (Self::RenderDeviceLost { .. }, Self::RenderDeviceLost { .. })
             | (Self::User { .. }, Self::User { .. })
             | (Self::Unknown { .. }, Self::Unknown { .. }) => true,
             #[cfg(feature = "hidapi")]
@@ -2247,6 +2330,7 @@ impl Event {
             Self::AudioDeviceRemoved { timestamp, .. } => timestamp,
             Self::RenderTargetsReset { timestamp, .. } => timestamp,
             Self::RenderDeviceReset { timestamp, .. } => timestamp,
+            Self::RenderDeviceLost { timestamp, .. } => timestamp,
             Self::User { timestamp, .. } => timestamp,
             Self::Unknown { timestamp, .. } => timestamp,
         }
@@ -2283,6 +2367,10 @@ impl Event {
             Self::MouseButtonDown { window_id, .. } => Some(*window_id),
             Self::MouseButtonUp { window_id, .. } => Some(*window_id),
             Self::MouseWheel { window_id, .. } => Some(*window_id),
+            Self::FingerDown { window_id, .. } => Some(*window_id),
+            Self::FingerUp { window_id, .. } => Some(*window_id),
+            Self::FingerMotion { window_id, .. } => Some(*window_id),
+            Self::RenderDeviceLost { window_id, .. } => Some(*window_id),
             Self::DropFile { window_id, .. } => Some(*window_id),
             Self::DropText { window_id, .. } => Some(*window_id),
             Self::DropBegin { window_id, .. } => Some(*window_id),
@@ -2487,6 +2575,7 @@ impl Event {
     ///
     /// let ev = Event::FingerMotion {
     ///     timestamp: 0,
+    ///     window_id: 0,
     ///     touch_id: 0,
     ///     finger_id: 0,
     ///     x: 0.,
@@ -2583,7 +2672,9 @@ impl Event {
     pub fn is_render(&self) -> bool {
         matches!(
             self,
-            Self::RenderTargetsReset { .. } | Self::RenderDeviceReset { .. }
+            Self::RenderTargetsReset { .. }
+                | Self::RenderDeviceReset { .. }
+                | Self::RenderDeviceLost { .. }
         )
     }
 
@@ -2904,6 +2995,21 @@ mod test {
             let e2 = Event::from_ll(e.clone().to_ll().unwrap());
             assert_eq!(e, e2);
         }
+        {
+            let e = Event::FingerMotion {
+                timestamp: 0,
+                window_id: 2,
+                touch_id: 1,
+                finger_id: 3,
+                x: 0.25,
+                y: 0.5,
+                dx: 0.125,
+                dy: -0.125,
+                pressure: 1.,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
         {
             let e = Event::MouseButtonDown {
                 timestamp: 5634,
@@ -21,7 +21,7 @@
 //!
 //!     canvas.set_draw_color(Color::RGB(0, 255, 255));
 //!     canvas.clear();
-//!     canvas.present();
+//!     canvas.present().unwrap();
 //!     let mut event_pump = sdl_context.event_pump().unwrap();
 //!     let mut i = 0;
 //!     'running: loop {
@@ -39,7 +39,7 @@
 //!         }
 //!         // The rest of the game loop goes here...
 //!
-//!         canvas.present();
+//!         canvas.present().unwrap();
 //!         ::std::thread::sleep(Duration::new(0, 1_000_000_000u32 / 60));
 //!     }
 //! }
@@ -214,6 +214,232 @@ impl From<(u8, u8, u8, u8)> for Color {
     }
 }
 
+/// A color with `f32` channels, nominally in the `0.0..=1.0` range.
+///
+/// Unlike [`Color`], channels may exceed 1.0, which is useful for HDR and
+/// wide-gamut rendering.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct FColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl FColor {
+    #[inline]
+    #[allow(non_snake_case)]
+    pub const fn RGB(r: f32, g: f32, b: f32) -> FColor {
+        FColor { r, g, b, a: 1.0 }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub const fn RGBA(r: f32, g: f32, b: f32, a: f32) -> FColor {
+        FColor { r, g, b, a }
+    }
+
+    #[inline]
+    pub const fn to_ll(self) -> sys::pixels::SDL_FColor {
+        sys::pixels::SDL_FColor {
+            r: self.r,
+            g: self.g,
+            b: self.b,
+            a: self.a,
+        }
+    }
+
+    pub const WHITE: FColor = FColor::RGBA(1.0, 1.0, 1.0, 1.0);
+    pub const BLACK: FColor = FColor::RGBA(0.0, 0.0, 0.0, 1.0);
+    pub const RED: FColor = FColor::RGBA(1.0, 0.0, 0.0, 1.0);
+    pub const GREEN: FColor = FColor::RGBA(0.0, 1.0, 0.0, 1.0);
+    pub const BLUE: FColor = FColor::RGBA(0.0, 0.0, 1.0, 1.0);
+    pub const TRANSPARENT: FColor = FColor::RGBA(0.0, 0.0, 0.0, 0.0);
+}
+
+/// Scales each channel from `0..=255` to `0.0..=1.0`.
+impl From<Color> for FColor {
+    fn from(color: Color) -> FColor {
+        FColor::RGBA(
+            color.r as f32 / 255.0,
+            color.g as f32 / 255.0,
+            color.b as f32 / 255.0,
+            color.a as f32 / 255.0,
+        )
+    }
+}
+
+/// Scales each channel from `0.0..=1.0` to `0..=255`, clamping values that
+/// fall outside that range.
+impl From<FColor> for Color {
+    fn from(color: FColor) -> Color {
+        fn to_u8(c: f32) -> u8 {
+            (c * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+        Color::RGBA(
+            to_u8(color.r),
+            to_u8(color.g),
+            to_u8(color.b),
+            to_u8(color.a),
+        )
+    }
+}
+
+impl From<FColor> for sys::pixels::SDL_FColor {
+    fn from(color: FColor) -> Self {
+        color.to_ll()
+    }
+}
+
+impl From<sys::pixels::SDL_FColor> for FColor {
+    fn from(raw: sys::pixels::SDL_FColor) -> FColor {
+        FColor::RGBA(raw.r, raw.g, raw.b, raw.a)
+    }
+}
+
+impl From<(f32, f32, f32)> for FColor {
+    fn from((r, g, b): (f32, f32, f32)) -> FColor {
+        FColor::RGB(r, g, b)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for FColor {
+    fn from((r, g, b, a): (f32, f32, f32, f32)) -> FColor {
+        FColor::RGBA(r, g, b, a)
+    }
+}
+
+#[test]
+fn fcolor_color_round_trip() {
+    let color = Color::RGBA(0, 64, 128, 255);
+    assert_eq!(Color::from(FColor::from(color)), color);
+
+    let hdr = FColor::RGBA(2.0, -1.0, 0.5, 1.0);
+    assert_eq!(Color::from(hdr), Color::RGBA(255, 0, 128, 255));
+}
+
+#[test]
+fn fcolor_palette() {
+    assert_eq!(FColor::from(Color::WHITE), FColor::WHITE);
+    assert_eq!(FColor::from(Color::BLACK), FColor::BLACK);
+    assert_eq!(FColor::from(Color::RED), FColor::RED);
+    assert_eq!(FColor::from(Color::GREEN), FColor::GREEN);
+    assert_eq!(FColor::from(Color::BLUE), FColor::BLUE);
+    assert_eq!(Color::from(FColor::TRANSPARENT), Color::RGBA(0, 0, 0, 0));
+
+    let ll = FColor::RGBA(0.25, 0.5, 0.75, 1.0).to_ll();
+    assert_eq!((ll.r, ll.g, ll.b, ll.a), (0.25, 0.5, 0.75, 1.0));
+}
+
+/// Converts straight-alpha pixels into premultiplied alpha, i.e. multiplies the color channels
+/// of every pixel by its alpha.
+///
+/// The pixels are 4 bytes each, with alpha in the last byte, as in
+/// `SDL_PIXELFORMAT_RGBA32` or `SDL_PIXELFORMAT_BGRA32`. Upload the result with
+/// `Texture::update_premultiplied` to blend it correctly.
+///
+/// Panics if `src` and `dst` have different lengths, or if the length is not a multiple of 4.
+pub fn premultiply_alpha(src: &[u8], dst: &mut [u8]) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "src and dst must have the same length"
+    );
+    assert_eq!(src.len() % 4, 0, "the length must be a multiple of 4");
+    for (src, dst) in src.chunks_exact(4).zip(dst.chunks_exact_mut(4)) {
+        let alpha = src[3] as u16;
+        for (dst, &src) in dst[..3].iter_mut().zip(&src[..3]) {
+            *dst = ((src as u16 * alpha + 127) / 255) as u8;
+        }
+        dst[3] = src[3];
+    }
+}
+
+#[test]
+fn premultiply_alpha_pixels() {
+    let src = [255, 128, 0, 255, 255, 128, 0, 128, 200, 100, 50, 0];
+    let mut dst = [0; 12];
+    premultiply_alpha(&src, &mut dst);
+    assert_eq!(dst, [255, 128, 0, 255, 128, 64, 0, 128, 0, 0, 0, 0]);
+}
+
+/// The colorspace of pixel data, i.e. its color primaries, transfer characteristics and
+/// range.
+///
+/// SDL uses this to convert between SDR and HDR content. RGB textures default to `Srgb` and YUV
+/// textures to `Jpeg`.
+#[repr(u32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Colorspace {
+    Unknown = sys::pixels::SDL_COLORSPACE_UNKNOWN.0,
+    /// sRGB, the default colorspace for RGB content.
+    Srgb = sys::pixels::SDL_COLORSPACE_SRGB.0,
+    /// sRGB primaries with a linear transfer, used for HDR rendering with float textures.
+    SrgbLinear = sys::pixels::SDL_COLORSPACE_SRGB_LINEAR.0,
+    /// BT.2020 primaries with the PQ (ST 2084) transfer, used for HDR10 content.
+    Hdr10 = sys::pixels::SDL_COLORSPACE_HDR10.0,
+    /// Full range BT.601 YUV, the default colorspace for YUV content.
+    Jpeg = sys::pixels::SDL_COLORSPACE_JPEG.0,
+    /// Limited range BT.601 YUV, used for SDTV video.
+    Bt601Limited = sys::pixels::SDL_COLORSPACE_BT601_LIMITED.0,
+    /// Full range BT.601 YUV.
+    Bt601Full = sys::pixels::SDL_COLORSPACE_BT601_FULL.0,
+    /// Limited range BT.709 YUV, used for HDTV video.
+    Bt709Limited = sys::pixels::SDL_COLORSPACE_BT709_LIMITED.0,
+    /// Full range BT.709 YUV.
+    Bt709Full = sys::pixels::SDL_COLORSPACE_BT709_FULL.0,
+    /// Limited range BT.2020 YUV, used for UHD video.
+    Bt2020Limited = sys::pixels::SDL_COLORSPACE_BT2020_LIMITED.0,
+    /// Full range BT.2020 YUV.
+    Bt2020Full = sys::pixels::SDL_COLORSPACE_BT2020_FULL.0,
+}
+
+impl From<Colorspace> for sys::pixels::SDL_Colorspace {
+    fn from(colorspace: Colorspace) -> sys::pixels::SDL_Colorspace {
+        sys::pixels::SDL_Colorspace(colorspace as u32)
+    }
+}
+
+/// Colorspaces without a `Colorspace` variant map to `Colorspace::Unknown`.
+impl From<sys::pixels::SDL_Colorspace> for Colorspace {
+    fn from(colorspace: sys::pixels::SDL_Colorspace) -> Colorspace {
+        use sys::pixels::*;
+        match colorspace {
+            SDL_COLORSPACE_SRGB => Colorspace::Srgb,
+            SDL_COLORSPACE_SRGB_LINEAR => Colorspace::SrgbLinear,
+            SDL_COLORSPACE_HDR10 => Colorspace::Hdr10,
+            SDL_COLORSPACE_JPEG => Colorspace::Jpeg,
+            SDL_COLORSPACE_BT601_LIMITED => Colorspace::Bt601Limited,
+            SDL_COLORSPACE_BT601_FULL => Colorspace::Bt601Full,
+            SDL_COLORSPACE_BT709_LIMITED => Colorspace::Bt709Limited,
+            SDL_COLORSPACE_BT709_FULL => Colorspace::Bt709Full,
+            SDL_COLORSPACE_BT2020_LIMITED => Colorspace::Bt2020Limited,
+            SDL_COLORSPACE_BT2020_FULL => Colorspace::Bt2020Full,
+            _ => Colorspace::Unknown,
+        }
+    }
+}
+
+#[test]
+fn colorspace_round_trip() {
+    for colorspace in [
+        Colorspace::Unknown,
+        Colorspace::Srgb,
+        Colorspace::SrgbLinear,
+        Colorspace::Hdr10,
+        Colorspace::Jpeg,
+        Colorspace::Bt601Limited,
+        Colorspace::Bt601Full,
+        Colorspace::Bt709Limited,
+        Colorspace::Bt709Full,
+        Colorspace::Bt2020Limited,
+        Colorspace::Bt2020Full,
+    ] {
+        let raw = sys::pixels::SDL_Colorspace::from(colorspace);
+        assert_eq!(Colorspace::from(raw), colorspace);
+    }
+}
+
 pub struct PixelMasks {
     /// Bits per pixel; usually 15, 16, or 32
     pub bpp: u8,
@@ -306,6 +532,43 @@ impl PixelFormat {
         }
     }
 
+    /// Packs a color into a pixel value of this format.
+    ///
+    /// For formats with fewer than 32 bits per pixel, the pixel is stored in
+    /// the low bits of the returned value. See [`Color::to_u32`].
+    #[doc(alias = "SDL_MapRGBA")]
+    pub fn pack(self, color: Color) -> u32 {
+        color.to_u32(&self)
+    }
+
+    /// Unpacks a pixel value of this format into a color. See
+    /// [`Color::from_u32`].
+    #[doc(alias = "SDL_GetRGBA")]
+    pub fn unpack(self, pixel: u32) -> Color {
+        Color::from_u32(&self, pixel)
+    }
+
+    /// Calculates the pitch, in bytes, of one row of tightly-packed pixels
+    /// of the given width.
+    ///
+    /// For the planar YUV formats (`YV12`, `IYUV`, `NV12`, `NV21`) this is
+    /// the pitch of the Y plane, which is what `Texture::update` expects.
+    /// Packed YUV formats store two pixels per 4-byte macropixel, so odd
+    /// widths are rounded up.
+    pub fn pitch_for_width(self, width: u32) -> usize {
+        let width = width as usize;
+        match self.raw {
+            SDL_PixelFormat::YV12
+            | SDL_PixelFormat::IYUV
+            | SDL_PixelFormat::NV12
+            | SDL_PixelFormat::NV21 => width,
+            SDL_PixelFormat::YUY2 | SDL_PixelFormat::UYVY | SDL_PixelFormat::YVYU => {
+                width.div_ceil(2) * 4
+            }
+            _ => width * self.byte_size_per_pixel(),
+        }
+    }
+
     #[allow(clippy::match_same_arms)]
     pub fn byte_size_of_pixels(self, num_of_pixels: usize) -> usize {
         match self.raw {
@@ -411,6 +674,49 @@ impl PixelFormat {
     }
 }
 
+#[test]
+fn pack_unpack() {
+    let argb8888 = unsafe { PixelFormat::from_ll(SDL_PixelFormat::ARGB8888) };
+    let color = Color::RGBA(0x11, 0x22, 0x33, 0x44);
+    assert_eq!(argb8888.pack(color), 0x44112233);
+    assert_eq!(argb8888.unpack(0x44112233), color);
+
+    let rgb565 = unsafe { PixelFormat::from_ll(SDL_PixelFormat::RGB565) };
+    assert_eq!(rgb565.pack(Color::RGB(0xff, 0, 0)), 0xf800);
+    assert_eq!(rgb565.unpack(0xf800), Color::RGB(0xff, 0, 0));
+}
+
+#[test]
+fn pitch_for_width_packed() {
+    let argb8888 = unsafe { PixelFormat::from_ll(SDL_PixelFormat::ARGB8888) };
+    assert_eq!(argb8888.pitch_for_width(0), 0);
+    assert_eq!(argb8888.pitch_for_width(17), 68);
+
+    let rgb565 = unsafe { PixelFormat::from_ll(SDL_PixelFormat::RGB565) };
+    assert_eq!(rgb565.pitch_for_width(17), 34);
+}
+
+#[test]
+fn pitch_for_width_24bit() {
+    let rgb24 = unsafe { PixelFormat::from_ll(SDL_PixelFormat::RGB24) };
+    assert_eq!(rgb24.pitch_for_width(1), 3);
+    assert_eq!(rgb24.pitch_for_width(101), 303);
+}
+
+#[test]
+fn pitch_for_width_yuv() {
+    let iyuv = unsafe { PixelFormat::from_ll(SDL_PixelFormat::IYUV) };
+    assert_eq!(iyuv.pitch_for_width(64), 64);
+    assert_eq!(
+        iyuv.byte_size_from_pitch_and_height(iyuv.pitch_for_width(64), 32),
+        64 * 32 * 3 / 2
+    );
+
+    let yuy2 = unsafe { PixelFormat::from_ll(SDL_PixelFormat::YUY2) };
+    assert_eq!(yuy2.pitch_for_width(64), 128);
+    assert_eq!(yuy2.pitch_for_width(65), 132);
+}
+
 impl From<PixelFormat> for SDL_PixelFormat {
     fn from(pf: PixelFormat) -> SDL_PixelFormat {
         pf.raw
@@ -22,6 +22,9 @@
 //!
 //! This API is not designed to be used from multiple threads, see
 //! [this bug](http://bugzilla.libsdl.org/show_bug.cgi?id=1995) for details.
+//! `Canvas` and `Texture` are `!Send`, and in debug builds the common drawing methods
+//! additionally panic if they are called from a thread other than the one that created the
+//! renderer.
 //!
 //! ---
 //!
@@ -29,6 +32,7 @@
 //! If they do, a panic is raised and the program is aborted.
 
 use crate::common::{validate_int, IntegerOrSdlError};
+use crate::event::Event;
 use crate::get_error;
 use crate::pixels;
 use crate::rect::Point;
@@ -39,28 +43,62 @@ use crate::video::{Window, WindowContext};
 use crate::Error;
 use libc::{c_double, c_int};
 use pixels::PixelFormat;
+use std::collections::HashMap;
 use std::convert::{Into, TryFrom, TryInto};
 use std::error;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt;
 #[cfg(not(feature = "unsafe_textures"))]
 use std::marker::PhantomData;
 use std::mem;
-use std::mem::{transmute, MaybeUninit};
-use std::ops::Deref;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
 use std::ptr;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use sys::blendmode::SDL_BlendMode;
 use sys::everything::SDL_PropertiesID;
 use sys::render::{SDL_GetTextureProperties, SDL_TextureAccess};
-use sys::stdinc::Sint64;
 use sys::surface::{SDL_FLIP_HORIZONTAL, SDL_FLIP_NONE, SDL_FLIP_VERTICAL};
 
+/// The size in pixels of a glyph of the built-in debug font used by
+/// [`Canvas::render_debug_text`]. Glyphs are square.
+pub const DEBUG_TEXT_FONT_CHARACTER_SIZE: u32 =
+    sys::render::SDL_DEBUG_TEXT_FONT_CHARACTER_SIZE as u32;
+
+/// Draws formatted debug text on a `Canvas`.
+///
+/// `render_debug_text!(canvas, position, "format", args...)` is shorthand for
+/// [`Canvas::render_debug_text_fmt`], where `position` is anything that converts into an
+/// [`FPoint`].
+///
+/// ```rust,no_run
+/// # use sdl3::render::Canvas;
+/// # use sdl3::video::Window;
+/// # let mut canvas : Canvas<Window> = unimplemented!();
+/// let fps = 60.0;
+/// sdl3::render_debug_text!(canvas, (10.0, 10.0), "fps: {:.1}", fps).unwrap();
+/// ```
+#[macro_export]
+macro_rules! render_debug_text {
+    ($canvas:expr, $position:expr, $($arg:tt)+) => {
+        $canvas.render_debug_text_fmt(
+            ::std::convert::Into::<$crate::render::FPoint>::into($position),
+            ::std::format_args!($($arg)+),
+        )
+    };
+}
+
 /// Possible errors returned by targeting a `Canvas` to render to a `Texture`
 #[derive(Debug, Clone)]
 pub enum TargetRenderError {
     SdlError(Error),
+    /// The texture can't be used as a render target, because it wasn't created with
+    /// `TextureAccess::Target`. Holds the access it was created with instead.
+    NotSupported(TextureAccess),
 }
 
 impl fmt::Display for TargetRenderError {
@@ -68,6 +106,12 @@ impl fmt::Display for TargetRenderError {
         use self::TargetRenderError::*;
         match *self {
             SdlError(ref e) => write!(f, "SDL error: {}", e),
+            NotSupported(access) => write!(
+                f,
+                "The texture can't be used as a render target: it was created with {:?} access \
+                 instead of TextureAccess::Target",
+                access
+            ),
         }
     }
 }
@@ -77,8 +121,264 @@ impl error::Error for TargetRenderError {
         use self::TargetRenderError::*;
         match self {
             SdlError(e) => &e.0,
+            NotSupported(_) => "The texture can't be used as a render target",
+        }
+    }
+}
+
+/// Combines horizontal and vertical flipping into a `SDL_FlipMode`, whose values are bit flags.
+fn flip_mode(horizontal: bool, vertical: bool) -> sys::surface::SDL_FlipMode {
+    let mut flip = SDL_FLIP_NONE.0;
+    if horizontal {
+        flip |= SDL_FLIP_HORIZONTAL.0;
+    }
+    if vertical {
+        flip |= SDL_FLIP_VERTICAL.0;
+    }
+    sys::surface::SDL_FlipMode(flip)
+}
+
+/// Gets the number of sides used to approximate a circle of the given radius in
+/// [`Canvas::fill_circle`] and [`Canvas::draw_circle`].
+///
+/// This is `4 * sqrt(radius)`, clamped to `8..=256`. With that many sides, the polygon never
+/// strays more than about a third of a pixel from the true circle, whatever the radius.
+pub fn circle_segments(radius: f32) -> usize {
+    let segments = (radius.max(0.0).sqrt() * 4.0).ceil();
+    // `as` saturates, and maps NaN to 0.
+    (segments as usize).clamp(8, 256)
+}
+
+unsafe fn set_raw_clip_rect(renderer: *mut sys::render::SDL_Renderer, clip: ClippingRect) -> bool {
+    match clip {
+        ClippingRect::Some(r) => sdl3_sys::everything::SDL_SetRenderClipRect(renderer, r.raw()),
+        ClippingRect::Zero => {
+            let r = sdl3_sys::everything::SDL_Rect {
+                x: 0,
+                y: 0,
+                w: 0,
+                h: 0,
+            };
+            let r: *const sdl3_sys::everything::SDL_Rect = &r;
+            sdl3_sys::everything::SDL_SetRenderClipRect(renderer, r)
+        }
+        ClippingRect::None => sdl3_sys::everything::SDL_SetRenderClipRect(renderer, ptr::null()),
+    }
+}
+
+/// Restores a piece of renderer state when dropped, including when unwinding from a panic.
+enum RestoreRenderState {
+    Viewport(*mut sys::render::SDL_Renderer, Option<Rect>),
+    ClipRect(*mut sys::render::SDL_Renderer, ClippingRect),
+    DrawColor(*mut sys::render::SDL_Renderer, pixels::FColor),
+    BlendMode(*mut sys::render::SDL_Renderer, SDL_BlendMode),
+}
+
+impl Drop for RestoreRenderState {
+    fn drop(&mut self) {
+        // Errors are ignored: they can't be reported from here, and SDL only fails on an
+        // invalid renderer.
+        match *self {
+            RestoreRenderState::Viewport(renderer, rect) => {
+                let ptr = rect.as_ref().map_or(ptr::null(), |rect| rect.raw());
+                unsafe { sys::render::SDL_SetRenderViewport(renderer, ptr) };
+            }
+            RestoreRenderState::ClipRect(renderer, clip) => {
+                unsafe { set_raw_clip_rect(renderer, clip) };
+            }
+            RestoreRenderState::DrawColor(renderer, color) => {
+                unsafe {
+                    sys::render::SDL_SetRenderDrawColorFloat(
+                        renderer, color.r, color.g, color.b, color.a,
+                    )
+                };
+            }
+            RestoreRenderState::BlendMode(renderer, blend) => {
+                unsafe { sys::render::SDL_SetRenderDrawBlendMode(renderer, blend) };
+            }
+        }
+    }
+}
+
+/// Splits `len` units starting at `start` into runs that don't cross a multiple of `size`.
+///
+/// Each run is `(start within 0..size, length, offset from start)`.
+fn wrap_segments(start: f32, len: f32, size: f32) -> Vec<(f32, f32, f32)> {
+    let mut segments = Vec::new();
+    if !(size > 0.0 && start.is_finite() && len.is_finite()) {
+        return segments;
+    }
+    let mut offset = 0.0;
+    while offset < len {
+        let mut position = (start + offset).rem_euclid(size);
+        // rem_euclid can round up to `size` for tiny negative values
+        if position >= size {
+            position = 0.0;
+        }
+        let length = (size - position).min(len - offset);
+        // Past 2^24, adding a small length can leave `offset` unchanged.
+        if offset + length <= offset {
+            break;
+        }
+        segments.push((position, length, offset));
+        offset += length;
+    }
+    segments
+}
+
+/// Upper bound on the number of `copy` calls a single [`Canvas::copy_wrapped`] may issue.
+const MAX_WRAPPED_TILES: f32 = 65536.0;
+
+/// Upper bound on the number of dashes a single [`Canvas::draw_dashed_line`] may draw.
+const MAX_DASHES: f32 = 65536.0;
+
+/// Sleeps until `deadline`, spinning for the last `SPIN_THRESHOLD` for precision.
+fn sleep_until(deadline: Instant) {
+    const SPIN_THRESHOLD: Duration = Duration::from_millis(2);
+    loop {
+        let now = Instant::now();
+        if now >= deadline {
+            return;
+        }
+        let remaining = deadline - now;
+        if remaining > SPIN_THRESHOLD {
+            std::thread::sleep(remaining - SPIN_THRESHOLD);
+        } else {
+            std::hint::spin_loop();
+        }
+    }
+}
+
+fn validate_scale(scale_x: f32, scale_y: f32) -> Result<(), Error> {
+    if !(scale_x.is_finite() && scale_x > 0.0 && scale_y.is_finite() && scale_y > 0.0) {
+        Err(Error(format!(
+            "invalid render scale: ({}, {})",
+            scale_x, scale_y
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_circle(radius: f32, segments: usize) -> Result<(), Error> {
+    if radius.is_nan() || radius < 0.0 {
+        Err(Error(format!("invalid circle radius: {}", radius)))
+    } else if segments < 3 {
+        Err(Error(format!(
+            "a circle needs at least 3 segments, got {}",
+            segments
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Points around a circle, starting and ending at the rightmost one (`segments + 1` points).
+fn circle_points(center: FPoint, radius: f32, segments: usize) -> impl Iterator<Item = FPoint> {
+    (0..=segments).map(move |i| {
+        let angle = i as f32 * std::f32::consts::TAU / segments as f32;
+        FPoint::new(
+            center.x + radius * angle.cos(),
+            center.y + radius * angle.sin(),
+        )
+    })
+}
+
+/// The "on" segments of a dashed line from `start` to `end`, starting with a full dash. The
+/// last dash is cut short at `end`.
+fn dash_segments(
+    start: FPoint,
+    end: FPoint,
+    dash_len: f32,
+    gap_len: f32,
+) -> Result<Vec<(FPoint, FPoint)>, Error> {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let length = dx.hypot(dy);
+    if length == 0.0 {
+        return Ok(Vec::new());
+    }
+    let point_at = |distance: f32| {
+        let t = distance / length;
+        FPoint::new(start.x + dx * t, start.y + dy * t)
+    };
+    let period = dash_len + gap_len;
+    let dashes = (length / period).ceil();
+    if dashes > MAX_DASHES {
+        return Err(Error(format!(
+            "too many dashes: {} (at most {})",
+            dashes, MAX_DASHES
+        )));
+    }
+    Ok((0..dashes as usize)
+        .map(|i| i as f32 * period)
+        .filter(|&from| from < length)
+        .map(|from| (point_at(from), point_at((from + dash_len).min(length))))
+        .collect())
+}
+
+/// Triangles covering a line of the given width, as points and indices into them.
+fn thick_line_geometry(
+    start: FPoint,
+    end: FPoint,
+    width: f32,
+    cap: LineCap,
+) -> (Vec<FPoint>, Vec<i32>) {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let length = dx.hypot(dy);
+    if length == 0.0 && cap == LineCap::Butt {
+        return (Vec::new(), Vec::new());
+    }
+    // A zero-length line with round caps is a dot, in any direction.
+    let (ux, uy) = if length == 0.0 {
+        (1.0, 0.0)
+    } else {
+        (dx / length, dy / length)
+    };
+    let half = width / 2.0;
+    let (nx, ny) = (-uy * half, ux * half);
+
+    let mut points = vec![
+        FPoint::new(start.x + nx, start.y + ny),
+        FPoint::new(start.x - nx, start.y - ny),
+        FPoint::new(end.x - nx, end.y - ny),
+        FPoint::new(end.x + nx, end.y + ny),
+    ];
+    let mut indices = vec![0, 1, 2, 0, 2, 3];
+    if cap == LineCap::Round {
+        let segments = (circle_segments(half) / 2).max(4);
+        // Half circles from one side of the line to the other, around the outside of each end.
+        for (center, (from_x, from_y)) in [(start, (nx, ny)), (end, (-nx, -ny))] {
+            let first_angle = from_y.atan2(from_x);
+            let center_index = points.len() as i32;
+            points.push(center);
+            points.extend((0..=segments).map(|i| {
+                let angle = first_angle + i as f32 * std::f32::consts::PI / segments as f32;
+                FPoint::new(center.x + half * angle.cos(), center.y + half * angle.sin())
+            }));
+            for i in 1..=segments as i32 {
+                indices.extend_from_slice(&[center_index, center_index + i, center_index + i + 1]);
+            }
         }
     }
+    (points, indices)
+}
+
+/// Indices of a triangle fan over the first vertex of a polygon with `vertices` vertices.
+fn polygon_fan_indices(vertices: usize) -> Vec<i32> {
+    (1..vertices.saturating_sub(1) as i32)
+        .flat_map(|i| [0, i, i + 1])
+        .collect()
+}
+
+/// Checks that a texture was created with `TextureAccess::Target`, so that it can be passed to
+/// `SDL_SetRenderTarget`.
+fn check_render_target(raw: *mut sys::render::SDL_Texture) -> Result<(), TargetRenderError> {
+    let access = InternalTexture { raw }.get_access();
+    if access == TextureAccess::Target {
+        Ok(())
+    } else {
+        Err(TargetRenderError::NotSupported(access))
+    }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -158,7 +458,76 @@ impl From<(u32, u32)> for FPoint {
     }
 }
 
+/// Collects points to draw them all at once with a single [`Canvas::draw_points`] call.
+///
+/// The buffer keeps its capacity across [`PointBatch::flush`]es, so a batch reused every frame
+/// (e.g. for a particle system) stops allocating once it has grown large enough.
+///
+/// ```rust,no_run
+/// # use sdl3::render::{Canvas, PointBatch};
+/// # use sdl3::video::Window;
+/// # let mut canvas : Canvas<Window> = unimplemented!();
+/// let mut batch = PointBatch::new();
+/// for i in 0..100 {
+///     batch.push((i as f32, i as f32 * 0.5));
+/// }
+/// batch.flush(&mut canvas).unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PointBatch {
+    points: Vec<FPoint>,
+}
+
+impl PointBatch {
+    pub fn new() -> PointBatch {
+        PointBatch { points: Vec::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> PointBatch {
+        PointBatch {
+            points: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a point to the batch.
+    #[inline]
+    pub fn push<P: Into<FPoint>>(&mut self, point: P) {
+        self.points.push(point.into());
+    }
+
+    /// Adds every point of an iterator to the batch.
+    pub fn extend<P: Into<FPoint>, I: IntoIterator<Item = P>>(&mut self, points: I) {
+        self.points.extend(points.into_iter().map(Into::into));
+    }
+
+    /// Gets the number of points waiting to be drawn.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Draws every point of the batch on `canvas`, then empties the batch.
+    ///
+    /// The batch is emptied even if drawing fails.
+    pub fn flush<T: RenderTarget>(&mut self, canvas: &mut Canvas<T>) -> Result<(), Error> {
+        if self.points.is_empty() {
+            return Ok(());
+        }
+        let result = canvas.draw_points(&self.points[..]);
+        self.points.clear();
+        result
+    }
+}
+
 // floating-point rectangle
+//
+// Layout-identical to `SDL_FRect`, so slices of `FRect` can be passed to SDL without copying.
+#[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct FRect {
     pub x: f32,
@@ -166,6 +535,11 @@ pub struct FRect {
     pub w: f32,
     pub h: f32,
 }
+
+const _: () = assert!(
+    mem::size_of::<FRect>() == mem::size_of::<sys::rect::SDL_FRect>()
+        && mem::align_of::<FRect>() == mem::align_of::<sys::rect::SDL_FRect>()
+);
 impl FRect {
     pub fn new(x: f32, y: f32, w: f32, h: f32) -> FRect {
         FRect { x, y, w, h }
@@ -194,6 +568,66 @@ impl FRect {
         self.x = update.x;
         self.y = update.y;
     }
+
+    /// Returns `true` if the rect has no area, i.e. its width or height is zero or negative.
+    pub fn is_empty(&self) -> bool {
+        !(self.w > 0.0 && self.h > 0.0)
+    }
+
+    /// Checks whether a point is inside the rect.
+    ///
+    /// The left and top edges are inside the rect, the right and bottom edges are not, so a
+    /// rect with no area contains no points.
+    pub fn contains_point<P: Into<FPoint>>(&self, point: P) -> bool {
+        let point = point.into();
+        !self.is_empty()
+            && point.x >= self.x
+            && point.x < self.x + self.w
+            && point.y >= self.y
+            && point.y < self.y + self.h
+    }
+
+    /// Checks whether two rects overlap. Rects that only share an edge, or that have no
+    /// area, don't intersect.
+    pub fn has_intersection(&self, other: FRect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Calculates the intersection of two rects.
+    ///
+    /// Returns `None` if the rects don't overlap, or if either of them has no area.
+    pub fn intersection(&self, other: FRect) -> Option<FRect> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let w = (self.x + self.w).min(other.x + other.w) - x;
+        let h = (self.y + self.h).min(other.y + other.h) - y;
+        let rect = FRect::new(x, y, w, h);
+        if rect.is_empty() {
+            None
+        } else {
+            Some(rect)
+        }
+    }
+
+    /// Calculates the smallest rect containing both rects.
+    ///
+    /// A rect with no area is ignored, so the union with it is the other rect.
+    pub fn union(&self, other: FRect) -> FRect {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return other;
+        }
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let w = (self.x + self.w).max(other.x + other.w) - x;
+        let h = (self.y + self.h).max(other.y + other.h) - y;
+        FRect::new(x, y, w, h)
+    }
 }
 
 impl From<Rect> for FRect {
@@ -208,6 +642,60 @@ impl From<(i32, i32, u32, u32)> for FRect {
     }
 }
 
+/// A vertex for [`Canvas::render_geometry`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vertex {
+    /// Vertex position, in render coordinates.
+    pub position: FPoint,
+    /// Vertex color, multiplied with the texture color if there is one.
+    pub color: pixels::FColor,
+    /// Normalized texture coordinates, if a texture is used.
+    pub tex_coord: FPoint,
+}
+
+impl Vertex {
+    pub fn new<C: Into<pixels::FColor>>(position: FPoint, color: C, tex_coord: FPoint) -> Vertex {
+        Vertex {
+            position,
+            color: color.into(),
+            tex_coord,
+        }
+    }
+
+    pub fn to_ll(&self) -> sys::render::SDL_Vertex {
+        sys::render::SDL_Vertex {
+            position: self.position.to_ll(),
+            color: self.color.into(),
+            tex_coord: self.tex_coord.to_ll(),
+        }
+    }
+}
+
+/// How the ends of a thick line are drawn, for [`Canvas::draw_line_thick_with_cap`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum LineCap {
+    /// The line ends flat, exactly at its end points.
+    Butt,
+    /// The line ends with half circles around its end points, which extend it by half its
+    /// width at both ends.
+    Round,
+}
+
+/// One copy of a texture region, for [`Canvas::copy_batch`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SpriteInstance {
+    /// The region of the texture to copy, in pixels.
+    pub src: FRect,
+    /// Where to draw it, in render coordinates.
+    pub dst: FRect,
+}
+
+impl SpriteInstance {
+    pub fn new(src: FRect, dst: FRect) -> SpriteInstance {
+        SpriteInstance { src, dst }
+    }
+}
+
 #[derive(Debug)]
 pub struct InvalidTextureAccess(u32);
 
@@ -241,6 +729,10 @@ impl TryFrom<u32> for TextureAccess {
 
 /// A structure that contains information on the capabilities of a render driver
 /// or the current render context.
+#[deprecated(
+    note = "leftover from SDL2, never returned by SDL3; use `Canvas::name`, \
+            `Canvas::max_texture_size` and `Canvas::supported_texture_formats` instead"
+)]
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub struct RendererInfo {
     pub name: &'static str,
@@ -250,49 +742,270 @@ pub struct RendererInfo {
     pub max_texture_height: u32,
 }
 
+/// The properties of a renderer, read from `SDL_GetRendererProperties`.
+///
+/// Returned by [`Canvas::get_properties`]. This is a snapshot: properties that can change,
+/// like `hdr_enabled`, are not kept up to date.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RendererProperties {
+    /// The name of the rendering driver.
+    pub name: String,
+    /// The maximum texture width and height.
+    pub max_texture_size: u32,
+    /// The texture formats supported by the renderer.
+    pub texture_formats: Vec<PixelFormat>,
+    /// The colorspace used for output.
+    pub output_colorspace: pixels::Colorspace,
+    /// Whether the output colorspace is an HDR colorspace and HDR is currently enabled.
+    pub hdr_enabled: bool,
+    /// The value of SDR white in the linear colorspace.
+    pub sdr_white_point: f32,
+    /// The additional high dynamic range that can be displayed, in terms of the SDR white point.
+    pub hdr_headroom: f32,
+}
+
 /// Blend mode for `Canvas`, `Texture` or `Surface`.
-#[repr(i32)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum BlendMode {
     /// no blending (replace destination with source).
-    None = sys::blendmode::SDL_BLENDMODE_NONE as i32,
+    None,
     /// Alpha blending
     ///
     /// dstRGB = (srcRGB * srcA) + (dstRGB * (1-srcA))
     ///
     /// dstA = srcA + (dstA * (1-srcA))
-    Blend = sys::blendmode::SDL_BLENDMODE_BLEND as i32,
+    Blend,
     /// Additive blending
     ///
     /// dstRGB = (srcRGB * srcA) + dstRGB
     ///
     /// dstA = dstA (keep original alpha)
-    Add = sys::blendmode::SDL_BLENDMODE_ADD as i32,
+    Add,
     /// Color modulate
     ///
     /// dstRGB = srcRGB * dstRGB
-    Mod = sys::blendmode::SDL_BLENDMODE_MOD as i32,
+    Mod,
     /// Color multiply
-    Mul = sys::blendmode::SDL_BLENDMODE_MUL as i32,
+    Mul,
     /// Invalid blending mode (indicates error)
-    Invalid = sys::blendmode::SDL_BLENDMODE_INVALID as i32,
+    Invalid,
+    /// A custom blend mode composed with [`CustomBlendMode`], as returned by the `blend_mode`
+    /// getters. Can be passed back to `set_blend_mode`.
+    Custom(SDL_BlendMode),
 }
 
-impl TryFrom<u32> for BlendMode {
-    type Error = ();
+impl BlendMode {
+    /// Converts the blend mode into the raw value understood by SDL.
+    pub fn to_ll(self) -> SDL_BlendMode {
+        match self {
+            BlendMode::None => sys::blendmode::SDL_BLENDMODE_NONE,
+            BlendMode::Blend => sys::blendmode::SDL_BLENDMODE_BLEND,
+            BlendMode::Add => sys::blendmode::SDL_BLENDMODE_ADD,
+            BlendMode::Mod => sys::blendmode::SDL_BLENDMODE_MOD,
+            BlendMode::Mul => sys::blendmode::SDL_BLENDMODE_MUL,
+            BlendMode::Invalid => sys::blendmode::SDL_BLENDMODE_INVALID,
+            BlendMode::Custom(mode) => mode,
+        }
+    }
+}
 
-    fn try_from(n: u32) -> Result<Self, Self::Error> {
+impl From<CustomBlendMode> for BlendMode {
+    fn from(mode: CustomBlendMode) -> BlendMode {
+        BlendMode::Custom(mode.to_ll())
+    }
+}
+
+impl From<SDL_BlendMode> for BlendMode {
+    /// Converts a raw blend mode, such as one returned by SDL.
+    ///
+    /// Values that are neither a preset nor a valid composed custom blend mode map to
+    /// `BlendMode::Invalid` instead of failing.
+    fn from(n: SDL_BlendMode) -> BlendMode {
         use self::BlendMode::*;
 
-        Ok(match n {
+        match n {
             sys::blendmode::SDL_BLENDMODE_NONE => None,
             sys::blendmode::SDL_BLENDMODE_BLEND => Blend,
             sys::blendmode::SDL_BLENDMODE_ADD => Add,
             sys::blendmode::SDL_BLENDMODE_MOD => Mod,
             sys::blendmode::SDL_BLENDMODE_MUL => Mul,
-            sys::blendmode::SDL_BLENDMODE_INVALID => Invalid,
-            _ => return Err(()),
-        })
+            // Presets without a variant of their own.
+            sys::blendmode::SDL_BLENDMODE_BLEND_PREMULTIPLIED
+            | sys::blendmode::SDL_BLENDMODE_ADD_PREMULTIPLIED => Custom(n),
+            n if is_composed_blend_mode(n) => Custom(n),
+            _ => Invalid,
+        }
+    }
+}
+
+/// Whether `mode` has the layout produced by `SDL_ComposeCustomBlendMode`, with every
+/// operation and factor in range.
+fn is_composed_blend_mode(mode: SDL_BlendMode) -> bool {
+    let field = |shift: u32| ((mode >> shift) & 0xF) as i32;
+    let operation = |shift: u32| {
+        (sys::blendmode::SDL_BlendOperation::ADD.0..=sys::blendmode::SDL_BlendOperation::MAXIMUM.0)
+            .contains(&field(shift))
+    };
+    let factor = |shift: u32| {
+        (sys::blendmode::SDL_BlendFactor::ZERO.0
+            ..=sys::blendmode::SDL_BlendFactor::ONE_MINUS_DST_ALPHA.0)
+            .contains(&field(shift))
+    };
+    mode & 0xF000_F000 == 0
+        && operation(0)
+        && factor(4)
+        && factor(8)
+        && operation(16)
+        && factor(20)
+        && factor(24)
+}
+
+/// A factor applied to the source or destination color of a [`CustomBlendMode`].
+#[repr(i32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BlendFactor {
+    /// 0, 0, 0, 0
+    Zero = sys::blendmode::SDL_BlendFactor::ZERO.0,
+    /// 1, 1, 1, 1
+    One = sys::blendmode::SDL_BlendFactor::ONE.0,
+    /// srcR, srcG, srcB, srcA
+    SrcColor = sys::blendmode::SDL_BlendFactor::SRC_COLOR.0,
+    /// 1-srcR, 1-srcG, 1-srcB, 1-srcA
+    OneMinusSrcColor = sys::blendmode::SDL_BlendFactor::ONE_MINUS_SRC_COLOR.0,
+    /// srcA, srcA, srcA, srcA
+    SrcAlpha = sys::blendmode::SDL_BlendFactor::SRC_ALPHA.0,
+    /// 1-srcA, 1-srcA, 1-srcA, 1-srcA
+    OneMinusSrcAlpha = sys::blendmode::SDL_BlendFactor::ONE_MINUS_SRC_ALPHA.0,
+    /// dstR, dstG, dstB, dstA
+    DstColor = sys::blendmode::SDL_BlendFactor::DST_COLOR.0,
+    /// 1-dstR, 1-dstG, 1-dstB, 1-dstA
+    OneMinusDstColor = sys::blendmode::SDL_BlendFactor::ONE_MINUS_DST_COLOR.0,
+    /// dstA, dstA, dstA, dstA
+    DstAlpha = sys::blendmode::SDL_BlendFactor::DST_ALPHA.0,
+    /// 1-dstA, 1-dstA, 1-dstA, 1-dstA
+    OneMinusDstAlpha = sys::blendmode::SDL_BlendFactor::ONE_MINUS_DST_ALPHA.0,
+}
+
+impl From<BlendFactor> for sys::blendmode::SDL_BlendFactor {
+    fn from(factor: BlendFactor) -> Self {
+        sys::blendmode::SDL_BlendFactor(factor as i32)
+    }
+}
+
+/// The operation combining the source and destination terms of a [`CustomBlendMode`].
+#[repr(i32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BlendOperation {
+    /// dst + src: supported by all renderers
+    Add = sys::blendmode::SDL_BlendOperation::ADD.0,
+    /// src - dst: supported by D3D, OpenGL, OpenGLES, and Vulkan
+    Subtract = sys::blendmode::SDL_BlendOperation::SUBTRACT.0,
+    /// dst - src: supported by D3D, OpenGL, OpenGLES, and Vulkan
+    RevSubtract = sys::blendmode::SDL_BlendOperation::REV_SUBTRACT.0,
+    /// min(dst, src): supported by D3D, OpenGL, OpenGLES, and Vulkan
+    Minimum = sys::blendmode::SDL_BlendOperation::MINIMUM.0,
+    /// max(dst, src): supported by D3D, OpenGL, OpenGLES, and Vulkan
+    Maximum = sys::blendmode::SDL_BlendOperation::MAXIMUM.0,
+}
+
+impl From<BlendOperation> for sys::blendmode::SDL_BlendOperation {
+    fn from(operation: BlendOperation) -> Self {
+        sys::blendmode::SDL_BlendOperation(operation as i32)
+    }
+}
+
+/// A blend mode composed from custom blend factors and operations.
+///
+/// The color channels are computed as
+/// `dstRGB = colorOperation(srcRGB * srcColorFactor, dstRGB * dstColorFactor)`, and the
+/// alpha channel as `dstA = alphaOperation(srcA * srcAlphaFactor, dstA * dstAlphaFactor)`.
+///
+/// Not every renderer supports every combination; setting an unsupported mode with
+/// [`Canvas::set_custom_blend_mode`] or `Texture::set_custom_blend_mode` returns an error.
+///
+/// ```no_run
+/// use sdl3::render::{BlendFactor, BlendOperation, CustomBlendMode};
+///
+/// // Premultiplied alpha blending
+/// let premultiplied = CustomBlendMode::new()
+///     .src_color_factor(BlendFactor::One)
+///     .dst_color_factor(BlendFactor::OneMinusSrcAlpha)
+///     .src_alpha_factor(BlendFactor::One)
+///     .dst_alpha_factor(BlendFactor::OneMinusSrcAlpha);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CustomBlendMode {
+    src_color_factor: BlendFactor,
+    dst_color_factor: BlendFactor,
+    color_operation: BlendOperation,
+    src_alpha_factor: BlendFactor,
+    dst_alpha_factor: BlendFactor,
+    alpha_operation: BlendOperation,
+}
+
+impl Default for CustomBlendMode {
+    fn default() -> Self {
+        CustomBlendMode::new()
+    }
+}
+
+impl CustomBlendMode {
+    /// Creates a custom blend mode equivalent to `BlendMode::None`: the source replaces the
+    /// destination.
+    pub fn new() -> CustomBlendMode {
+        CustomBlendMode {
+            src_color_factor: BlendFactor::One,
+            dst_color_factor: BlendFactor::Zero,
+            color_operation: BlendOperation::Add,
+            src_alpha_factor: BlendFactor::One,
+            dst_alpha_factor: BlendFactor::Zero,
+            alpha_operation: BlendOperation::Add,
+        }
+    }
+
+    pub fn src_color_factor(mut self, factor: BlendFactor) -> CustomBlendMode {
+        self.src_color_factor = factor;
+        self
+    }
+
+    pub fn dst_color_factor(mut self, factor: BlendFactor) -> CustomBlendMode {
+        self.dst_color_factor = factor;
+        self
+    }
+
+    pub fn color_operation(mut self, operation: BlendOperation) -> CustomBlendMode {
+        self.color_operation = operation;
+        self
+    }
+
+    pub fn src_alpha_factor(mut self, factor: BlendFactor) -> CustomBlendMode {
+        self.src_alpha_factor = factor;
+        self
+    }
+
+    pub fn dst_alpha_factor(mut self, factor: BlendFactor) -> CustomBlendMode {
+        self.dst_alpha_factor = factor;
+        self
+    }
+
+    pub fn alpha_operation(mut self, operation: BlendOperation) -> CustomBlendMode {
+        self.alpha_operation = operation;
+        self
+    }
+
+    /// Composes the blend mode into the raw value understood by SDL.
+    #[doc(alias = "SDL_ComposeCustomBlendMode")]
+    pub fn to_ll(self) -> SDL_BlendMode {
+        unsafe {
+            sys::blendmode::SDL_ComposeCustomBlendMode(
+                self.src_color_factor.into(),
+                self.dst_color_factor.into(),
+                self.color_operation.into(),
+                self.src_alpha_factor.into(),
+                self.dst_alpha_factor.into(),
+                self.alpha_operation.into(),
+            )
+        }
     }
 }
 
@@ -363,6 +1076,33 @@ impl ClippingRect {
             }
         }
     }
+
+    /// Returns true if nothing can be drawn through this clipping rect, i.e. it is `Zero`.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, ClippingRect::Zero)
+    }
+
+    /// Returns true if drawing at `point` isn't clipped. `None` contains every point and
+    /// `Zero` contains none.
+    pub fn contains_point<P>(&self, point: P) -> bool
+    where
+        P: Into<(i32, i32)>,
+    {
+        match self {
+            ClippingRect::Some(rect) => rect.contains_point(point),
+            ClippingRect::Zero => false,
+            ClippingRect::None => true,
+        }
+    }
+
+    /// Gets the area that can be drawn to, in pixels, or `None` if it is unbounded.
+    pub fn area(&self) -> Option<u64> {
+        match self {
+            ClippingRect::Some(rect) => Some(rect.width() as u64 * rect.height() as u64),
+            ClippingRect::Zero => Some(0),
+            ClippingRect::None => None,
+        }
+    }
 }
 
 /// Manages what keeps a `SDL_Renderer` alive
@@ -371,17 +1111,51 @@ impl ClippingRect {
 pub struct RendererContext<T> {
     raw: *mut sys::render::SDL_Renderer,
     _target: Arc<T>,
+    /// Registered for renderers with a window; a software renderer can't lose its device.
+    device_lost: Option<Box<DeviceLostWatch>>,
+    #[cfg(debug_assertions)]
+    thread: std::thread::ThreadId,
 }
 
 impl<T> Drop for RendererContext<T> {
     #[doc(alias = "SDL_DestroyRenderer")]
     fn drop(&mut self) {
         unsafe {
+            if let Some(watch) = &self.device_lost {
+                sys::events::SDL_RemoveEventWatch(
+                    Some(watch_device_lost),
+                    &**watch as *const DeviceLostWatch as *mut libc::c_void,
+                );
+            }
             sys::render::SDL_DestroyRenderer(self.raw);
         };
     }
 }
 
+/// State shared with `watch_device_lost` for the renderer of one window.
+struct DeviceLostWatch {
+    window_id: u32,
+    /// Set by `watch_device_lost`, possibly from another thread.
+    lost: AtomicBool,
+}
+
+/// Event watch flagging the `DeviceLostWatch` behind `user_data` when the render device of its
+/// window is lost.
+extern "C" fn watch_device_lost(
+    user_data: *mut libc::c_void,
+    event: *mut sys::events::SDL_Event,
+) -> bool {
+    let watch = unsafe { &*(user_data as *const DeviceLostWatch) };
+    unsafe {
+        if (*event).r#type == sys::events::SDL_EVENT_RENDER_DEVICE_LOST.0
+            && (*event).render.windowID == watch.window_id
+        {
+            watch.lost.store(true, Ordering::Relaxed);
+        }
+    }
+    true
+}
+
 impl<T> RendererContext<T> {
     /// Gets the raw pointer to the SDL_Renderer
     // this can prevent introducing UB until
@@ -392,25 +1166,154 @@ impl<T> RendererContext<T> {
     }
 
     pub unsafe fn from_ll(raw: *mut sys::render::SDL_Renderer, target: Arc<T>) -> Self {
-        RendererContext {
+        let mut context = RendererContext {
             raw,
             _target: target,
-        }
+            device_lost: None,
+            #[cfg(debug_assertions)]
+            thread: std::thread::current().id(),
+        };
+        context.device_lost = context.window_id().map(|window_id| {
+            let watch = Box::new(DeviceLostWatch {
+                window_id,
+                lost: AtomicBool::new(false),
+            });
+            sys::events::SDL_AddEventWatch(
+                Some(watch_device_lost),
+                &*watch as *const DeviceLostWatch as *mut libc::c_void,
+            );
+            watch
+        });
+        context
+    }
+
+    /// Gets the raw pointer to the SDL_Renderer for a render call, after
+    /// [`debug_assert_thread`](Self::debug_assert_thread). Every `Canvas` method calling into
+    /// `SDL_Render*` goes through this.
+    #[inline]
+    fn checked_raw(&self) -> *mut sys::render::SDL_Renderer {
+        self.debug_assert_thread();
+        self.raw
     }
 
-    unsafe fn set_raw_target(
-        &self,
-        raw_texture: *mut sys::render::SDL_Texture,
-    ) -> Result<(), Error> {
-        if sys::render::SDL_SetRenderTarget(self.raw, raw_texture) {
-            Ok(())
-        } else {
-            Err(get_error())
-        }
+    /// Panics in debug builds if called from a thread other than the one that created the
+    /// renderer. Compiles to nothing in release builds.
+    #[inline]
+    fn debug_assert_thread(&self) {
+        #[cfg(debug_assertions)]
+        assert!(
+            std::thread::current().id() == self.thread,
+            "the renderer was used from a different thread than the one it was created on; \
+             SDL's render API must only be called from the thread that created the renderer"
+        );
     }
 
-    unsafe fn get_raw_target(&self) -> *mut sys::render::SDL_Texture {
-        sys::render::SDL_GetRenderTarget(self.raw)
+    /// Gets the ID of the window this renderer draws to.
+    ///
+    /// Returns `None` for renderers that have no window, such as software renderers created
+    /// from a `Surface`.
+    #[doc(alias = "SDL_GetRenderWindow")]
+    #[doc(alias = "SDL_GetWindowID")]
+    pub fn window_id(&self) -> Option<u32> {
+        let window = unsafe { sys::render::SDL_GetRenderWindow(self.raw) };
+        if window.is_null() {
+            return None;
+        }
+        match unsafe { sys::video::SDL_GetWindowID(window) } {
+            0 => None,
+            id => Some(id),
+        }
+    }
+
+    /// Sets the draw blend mode, and checks that it took: some backends accept modes they
+    /// can't actually draw with.
+    fn set_draw_blend_mode(&self, mode: SDL_BlendMode) -> Result<(), Error> {
+        let mut current: SDL_BlendMode = 0;
+        let ret = unsafe {
+            sys::render::SDL_SetRenderDrawBlendMode(self.raw, mode)
+                && sys::render::SDL_GetRenderDrawBlendMode(self.raw, &mut current)
+        };
+        if !ret {
+            Err(get_error())
+        } else if current != mode {
+            Err(Error(format!(
+                "the renderer does not support blend mode {:?}",
+                BlendMode::from(mode)
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    unsafe fn set_raw_target(
+        &self,
+        raw_texture: *mut sys::render::SDL_Texture,
+    ) -> Result<(), Error> {
+        if sys::render::SDL_SetRenderTarget(self.raw, raw_texture) {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    unsafe fn get_raw_target(&self) -> *mut sys::render::SDL_Texture {
+        sys::render::SDL_GetRenderTarget(self.raw)
+    }
+
+    #[doc(alias = "SDL_GetRendererProperties")]
+    fn get_raw_properties(&self) -> SDL_PropertiesID {
+        unsafe { sys::render::SDL_GetRendererProperties(self.raw) }
+    }
+
+    /// Reads a pointer property of the renderer, mapping a missing or null pointer to `None`.
+    fn get_pointer_property(&self, name: *const libc::c_char) -> Option<*mut libc::c_void> {
+        let pointer = unsafe {
+            sys::properties::SDL_GetPointerProperty(
+                self.get_raw_properties(),
+                name,
+                ptr::null_mut(),
+            )
+        };
+        if pointer.is_null() {
+            None
+        } else {
+            Some(pointer)
+        }
+    }
+
+    /// Reads a number property of the renderer, mapping a missing property to `None`.
+    fn get_number_property(&self, name: *const libc::c_char) -> Option<i64> {
+        let props = self.get_raw_properties();
+        unsafe {
+            if sys::properties::SDL_HasProperty(props, name) {
+                Some(sys::properties::SDL_GetNumberProperty(props, name, 0))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Reads the renderer's supported texture formats from its properties.
+    ///
+    /// SDL stores them as an array of `SDL_PixelFormat` terminated by
+    /// `SDL_PIXELFORMAT_UNKNOWN`.
+    fn get_texture_formats(&self) -> Vec<PixelFormat> {
+        let mut formats = Vec::new();
+        unsafe {
+            let mut ptr = sys::properties::SDL_GetPointerProperty(
+                self.get_raw_properties(),
+                sys::render::SDL_PROP_RENDERER_TEXTURE_FORMATS_POINTER,
+                ptr::null_mut(),
+            ) as *const sys::pixels::SDL_PixelFormat;
+            if ptr.is_null() {
+                return formats;
+            }
+            while *ptr != sys::pixels::SDL_PIXELFORMAT_UNKNOWN {
+                formats.push(PixelFormat::from_ll(*ptr));
+                ptr = ptr.add(1);
+            }
+        }
+        formats
     }
 }
 
@@ -422,16 +1325,79 @@ impl<T: RenderTarget> Deref for Canvas<T> {
     }
 }
 
+/// A snapshot of the transform between window and render coordinates.
+///
+/// Returned by [`Canvas::coordinate_mapper`]. It gives the same results as
+/// [`Canvas::render_coordinates_from_window`] and [`Canvas::render_coordinates_to_window`]
+/// without calling into SDL, which adds up when converting many touch points per frame.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RenderCoordinateMapper {
+    /// Pixels per window coordinate.
+    pixel_density: (f32, f32),
+    /// The logical size and where it is presented on the output, if logical presentation is
+    /// enabled.
+    logical: Option<((f32, f32), FRect)>,
+    scale: (f32, f32),
+    viewport_origin: (f32, f32),
+}
+
+impl RenderCoordinateMapper {
+    /// Converts a point from window coordinates to render coordinates.
+    pub fn to_render<P: Into<FPoint>>(&self, window_point: P) -> FPoint {
+        let point = window_point.into();
+        let mut x = point.x * self.pixel_density.0;
+        let mut y = point.y * self.pixel_density.1;
+        if let Some(((logical_w, logical_h), dst)) = self.logical {
+            x = (x - dst.x) * logical_w / dst.w;
+            y = (y - dst.y) * logical_h / dst.h;
+        }
+        FPoint::new(
+            x / self.scale.0 - self.viewport_origin.0,
+            y / self.scale.1 - self.viewport_origin.1,
+        )
+    }
+
+    /// Converts a point from render coordinates to window coordinates.
+    pub fn to_window<P: Into<FPoint>>(&self, render_point: P) -> FPoint {
+        let point = render_point.into();
+        let mut x = (point.x + self.viewport_origin.0) * self.scale.0;
+        let mut y = (point.y + self.viewport_origin.1) * self.scale.1;
+        if let Some(((logical_w, logical_h), dst)) = self.logical {
+            x = dst.x + x * dst.w / logical_w;
+            y = dst.y + y * dst.h / logical_h;
+        }
+        FPoint::new(x / self.pixel_density.0, y / self.pixel_density.1)
+    }
+}
+
+/// What a `Canvas` is currently rendering to. See [`Canvas::current_target`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RenderTargetKind {
+    /// The window the canvas was created from.
+    Window,
+    /// The surface the canvas was created from.
+    Surface,
+    /// A texture, e.g. inside [`Canvas::with_texture_canvas`].
+    Texture,
+}
+
 /// Represents structs which can be the target of a `SDL_Renderer` (or Canvas).
 ///
 /// This is intended for internal use only. It should not be used outside of this crate,
 /// but is still visible for documentation reasons.
 pub trait RenderTarget {
     type Context;
+
+    /// Gets the current pixel format of the target.
+    fn pixel_format(&self) -> PixelFormat;
 }
 
 impl<'s> RenderTarget for Surface<'s> {
     type Context = SurfaceContext<'s>;
+
+    fn pixel_format(&self) -> PixelFormat {
+        self.pixel_format_enum()
+    }
 }
 
 /// Manages and owns a target (`Surface` or `Window`) and allows drawing in it.
@@ -449,6 +1415,22 @@ impl<'s> RenderTarget for Surface<'s> {
 ///
 /// The context will not be dropped until all references of it are out of scope.
 ///
+/// # Device loss
+///
+/// On some platforms the render device can be reset or lost, for instance on a GPU reset or
+/// when a laptop wakes from sleep. SDL reports this with events rather than through the draw
+/// calls, which simply start failing with SDL errors:
+///
+/// * [`Event::RenderTargetsReset`](crate::event::Event::RenderTargetsReset): the contents of
+///   render target textures were lost and must be redrawn.
+/// * [`Event::RenderDeviceReset`](crate::event::Event::RenderDeviceReset): the contents of
+///   every texture were lost and must be re-uploaded.
+/// * [`Event::RenderDeviceLost`](crate::event::Event::RenderDeviceLost): the device can't be
+///   recovered. The `Canvas`, its `TextureCreator`s and all of their `Texture`s must be dropped
+///   and created again.
+///
+/// After a draw call or `present` fails, [`Canvas::is_valid`] tells whether the device was lost.
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -477,7 +1459,7 @@ impl<'s> RenderTarget for Surface<'s> {
 /// // but if we want our buffer to be displayed on the window,
 /// // we need to call `present`. We need to call this every time
 /// // we want to render a new frame on the window.
-/// canvas.present();
+/// canvas.present().unwrap();
 /// // present does not "clear" the buffer, that means that
 /// // you have to clear it yourself before rendering again,
 /// // otherwise leftovers of what you've renderer before might
@@ -490,8 +1472,14 @@ impl<'s> RenderTarget for Surface<'s> {
 pub struct Canvas<T: RenderTarget> {
     target: T,
     context: Rc<RendererContext<T::Context>>,
-    default_pixel_format: PixelFormat,
+    /// The name of the driver the renderer actually uses, as reported by SDL after creation.
+    /// This may differ from the driver that was requested; see [`Canvas::actual_driver`].
     pub renderer_name: String,
+    clip_stack: Vec<ClippingRect>,
+    draw_enabled: bool,
+    skip_transparent_copies: bool,
+    /// Scratch space reused by [`Canvas::render_debug_text_fmt`].
+    debug_text_buffer: String,
 }
 
 /// Alias for a `Canvas` that was created out of a `Surface`
@@ -509,22 +1497,65 @@ impl<'s> Canvas<Surface<'s>> {
         if !raw_renderer.is_null() {
             let context =
                 Rc::new(unsafe { RendererContext::from_ll(raw_renderer, surface.context()) });
-            let default_pixel_format = surface.pixel_format_enum();
             Ok(Canvas {
                 target: surface,
                 context,
-                default_pixel_format,
                 renderer_name: unsafe {
                     CStr::from_ptr(sys::render::SDL_GetRendererName(raw_renderer))
                         .to_string_lossy()
                         .into_owned()
                 },
+                clip_stack: Vec::new(),
+                draw_enabled: true,
+                skip_transparent_copies: false,
+                debug_text_buffer: String::new(),
             })
         } else {
             Err(get_error())
         }
     }
 
+    /// Creates a 2D software rendering context that draws straight into a caller-owned pixel
+    /// buffer, e.g. a framebuffer for an embedded display or a buffer checked by a test.
+    ///
+    /// `pitch` is the number of bytes in a row of `pixels`, including padding.
+    ///
+    /// Errors if `pitch` is too small for `width` pixels of `format`, if `pitch * height`
+    /// overflows or `pixels` is shorter than that many bytes, or if SDL fails to create the surface or the renderer.
+    #[doc(alias = "SDL_CreateSurfaceFrom")]
+    pub fn from_pixels(
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        pixels: &'s mut [u8],
+        pitch: usize,
+    ) -> Result<Self, Error> {
+        let min_pitch = format.pitch_for_width(width);
+        if pitch < min_pitch {
+            return Err(Error(format!(
+                "pitch {} is too small for {} pixels of {:?} ({} bytes)",
+                pitch, width, format, min_pitch
+            )));
+        }
+        let expected = pitch.checked_mul(height as usize).ok_or_else(|| {
+            Error(format!(
+                "pixel buffer size overflows ({} * {} bytes)",
+                pitch, height
+            ))
+        })?;
+        if pixels.len() < expected {
+            return Err(Error(format!(
+                "pixel buffer is too short ({} bytes, should be at least {} * {})",
+                pixels.len(),
+                pitch,
+                height
+            )));
+        }
+        let pitch = u32::try_from(pitch).map_err(|_| Error("Pitch is too large.".to_owned()))?;
+        let surface = Surface::from_data(pixels, width, height, pitch, format)?;
+        Canvas::from_surface(surface)
+    }
+
     /// Gets a reference to the associated surface of the Canvas
     #[inline]
     pub fn surface(&self) -> &SurfaceRef {
@@ -542,25 +1573,16 @@ impl<'s> Canvas<Surface<'s>> {
     pub fn into_surface(self) -> Surface<'s> {
         self.target
     }
-
-    /// Returns a `TextureCreator` that can create Textures to be drawn on this `Canvas`
-    ///
-    /// This `TextureCreator` will share a reference to the renderer and target context.
-    ///
-    /// The target (i.e., `Window`) will not be destroyed and the SDL_Renderer will not be
-    /// destroyed if the `TextureCreator` is still in scope.
-    pub fn texture_creator(&self) -> TextureCreator<SurfaceContext<'s>> {
-        TextureCreator {
-            context: self.context.clone(),
-            default_pixel_format: self.default_pixel_format,
-        }
-    }
 }
 
 pub type WindowCanvas = Canvas<Window>;
 
 impl RenderTarget for Window {
     type Context = WindowContext;
+
+    fn pixel_format(&self) -> PixelFormat {
+        self.window_pixel_format()
+    }
 }
 
 /// Methods for the `WindowCanvas`.
@@ -583,44 +1605,123 @@ impl Canvas<Window> {
         self.target
     }
 
-    #[inline]
-    pub fn default_pixel_format(&self) -> PixelFormat {
-        self.window().window_pixel_format()
+    /// Gets the refresh rate, in Hz, of the display the window is currently on.
+    ///
+    /// If the window is in exclusive fullscreen, this is the refresh rate of its fullscreen
+    /// mode; otherwise it's the refresh rate of the display's desktop mode. A refresh rate of
+    /// `0.0` means SDL does not know it.
+    pub fn display_refresh_rate(&self) -> Result<f32, Error> {
+        if let Some(mode) = self.window().display_mode() {
+            return Ok(mode.refresh_rate);
+        }
+        let display = self.window().get_display()?;
+        Ok(display.get_mode()?.refresh_rate)
     }
 
+    /// Creates a canvas from a window and a renderer created for it outside of this crate, e.g.
+    /// by a UI library's backend. The canvas takes ownership of the renderer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `renderer` is null or not a valid renderer. Use
+    /// [`Canvas::try_from_window_and_renderer`] to handle that case.
     pub fn from_window_and_renderer(
         window: Window,
         renderer: *mut sys::render::SDL_Renderer,
     ) -> Self {
+        Self::try_from_window_and_renderer(window, renderer)
+            .unwrap_or_else(|e| panic!("Could not create a canvas from the renderer: {}", e))
+    }
+
+    /// Creates a canvas from a window and a renderer created for it outside of this crate, e.g.
+    /// by a UI library's backend. The canvas takes ownership of the renderer.
+    ///
+    /// Returns an error, without taking ownership of the renderer, if `renderer` is null or not
+    /// a valid renderer.
+    #[doc(alias = "SDL_GetRendererName")]
+    pub fn try_from_window_and_renderer(
+        window: Window,
+        renderer: *mut sys::render::SDL_Renderer,
+    ) -> Result<Self, Error> {
+        if renderer.is_null() {
+            return Err(Error("renderer is null".to_owned()));
+        }
+        let name = unsafe { sys::render::SDL_GetRendererName(renderer) };
+        if name.is_null() {
+            return Err(get_error());
+        }
+        let renderer_name = unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned();
+
         let context = Rc::new(unsafe { RendererContext::from_ll(renderer, window.context()) });
-        let default_pixel_format = window.window_pixel_format();
-        Canvas::<Window> {
+        Ok(Canvas::<Window> {
             context,
             target: window,
-            default_pixel_format,
-            renderer_name: unsafe {
-                CStr::from_ptr(sys::render::SDL_GetRendererName(renderer))
-                    .to_string_lossy()
-                    .into_owned()
-            },
-        }
+            renderer_name,
+            clip_stack: Vec::new(),
+            draw_enabled: true,
+            skip_transparent_copies: false,
+            debug_text_buffer: String::new(),
+        })
+    }
+
+    /// Renders into a new surface through a temporary software canvas, and returns the
+    /// surface.
+    ///
+    /// The surface is `width` by `height` pixels and uses the window's pixel format. Drawing
+    /// code written against `Canvas<T: RenderTarget>` can be reused for both the window and the
+    /// export, e.g. to save a screenshot at a different resolution.
+    ///
+    /// Textures created by this canvas' `TextureCreator` cannot be used with the software
+    /// canvas; create them from `software_canvas.texture_creator()` instead.
+    ///
+    /// ```no_run
+    /// # fn draw_scene<T: sdl3::render::RenderTarget>(canvas: &mut sdl3::render::Canvas<T>) {}
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// # let window = video_subsystem.window("Example", 800, 600).build().unwrap();
+    /// let canvas = window.into_canvas();
+    /// let surface = canvas
+    ///     .render_to_surface((1920, 1080), |software_canvas| draw_scene(software_canvas))
+    ///     .unwrap();
+    /// ```
+    pub fn render_to_surface<F>(&self, size: (u32, u32), f: F) -> Result<Surface<'static>, Error>
+    where
+        F: FnOnce(&mut SurfaceCanvas<'static>),
+    {
+        let surface = Surface::new(size.0, size.1, self.default_pixel_format())?;
+        let mut software_canvas = SurfaceCanvas::from_surface(surface)?;
+        f(&mut software_canvas);
+        software_canvas.flush()?;
+        Ok(software_canvas.into_surface())
+    }
+}
+
+impl<T: RenderTarget> Canvas<T> {
+    /// Gets the current pixel format of the target (`Window` or `Surface`). Textures created
+    /// by [`Canvas::texture_creator`] default to it.
+    ///
+    /// The pixel format of a `Window` is queried each time, since it can change, e.g. when the
+    /// window moves to another display.
+    #[inline]
+    pub fn default_pixel_format(&self) -> PixelFormat {
+        self.target.pixel_format()
     }
 
     /// Returns a `TextureCreator` that can create Textures to be drawn on this `Canvas`
     ///
     /// This `TextureCreator` will share a reference to the renderer and target context.
     ///
-    /// The target (i.e., `Window`) will not be destroyed and the SDL_Renderer will not be
-    /// destroyed if the `TextureCreator` is still in scope.
-    pub fn texture_creator(&self) -> TextureCreator<WindowContext> {
+    /// The target (i.e., `Window` or `Surface`) will not be destroyed and the SDL_Renderer will
+    /// not be destroyed if the `TextureCreator` is still in scope.
+    pub fn texture_creator(&self) -> TextureCreator<T::Context> {
         TextureCreator {
             context: self.context.clone(),
             default_pixel_format: self.default_pixel_format(),
         }
     }
-}
 
-impl<T: RenderTarget> Canvas<T> {
     /// Temporarily sets the target of `Canvas` to a `Texture`. This effectively allows rendering
     /// to a `Texture` in any way you want: you can make a `Texture` a combination of other
     /// `Texture`s, be a complex geometry form with the `gfx` module, ... You can draw pixel by
@@ -653,15 +1754,10 @@ impl<T: RenderTarget> Canvas<T> {
     ///
     /// # Errors
     ///
-    /// * returns `TargetRenderError::NotSupported` if the renderer does not support the use of
-    /// render targets
+    /// * returns `TargetRenderError::NotSupported` if the texture was not created with the
+    /// texture access `sdl3::render::TextureAccess::Target`
     /// * returns `TargetRenderError::SdlError` if SDL2 returned with an error code.
     ///
-    /// The texture *must* be created with the texture access:
-    /// `sdl3::render::TextureAccess::Target`.
-    /// Using a texture which was not created with the texture access `Target` is undefined
-    /// behavior.
-    ///
     /// # Examples
     ///
     /// The example below changes a newly created `Texture` to be a 150-by-150 black texture with a
@@ -693,6 +1789,7 @@ impl<T: RenderTarget> Canvas<T> {
     where
         for<'r> F: FnOnce(&'r mut Canvas<T>),
     {
+        check_render_target(texture.raw)?;
         let target = unsafe { self.get_raw_target() };
         unsafe { self.set_raw_target(texture.raw) }.map_err(|e| TargetRenderError::SdlError(e))?;
         f(self);
@@ -706,7 +1803,9 @@ impl<T: RenderTarget> Canvas<T> {
     /// iteration either; the check is actually only done once, at the beginning, avoiding useless
     /// checks.
     ///
-    /// The closure is run once for every `Texture` sent as parameter.
+    /// The closure is run once for every `Texture` sent as parameter. Every `Texture` is checked
+    /// to have been created with `TextureAccess::Target` before any of them is rendered to;
+    /// otherwise `TargetRenderError::NotSupported` is returned and the closure is never run.
     ///
     /// The main changes from `with_texture_canvas` is that is takes an `Iterator` of `(&mut
     /// Texture, U)`, where U is a type defined by the user. The closure takes a `&mut Canvas`, and
@@ -766,6 +1865,12 @@ impl<T: RenderTarget> Canvas<T> {
         for<'r> F: FnMut(&'r mut Canvas<T>, &U),
         I: Iterator<Item = &'s (&'a mut Texture<'t>, U)>,
     {
+        // Validate every texture before changing the target, so SDL is never handed a texture
+        // that can't be rendered to.
+        let textures: Vec<_> = textures.collect();
+        for (texture, _) in &textures {
+            check_render_target(texture.raw)?;
+        }
         let target = unsafe { self.get_raw_target() };
         for (texture, user_context) in textures {
             unsafe { self.set_raw_target(texture.raw) }
@@ -787,6 +1892,12 @@ impl<T: RenderTarget> Canvas<T> {
         for<'r> F: FnMut(&'r mut Canvas<T>, &U),
         I: Iterator<Item = &'s (&'a mut Texture, U)>,
     {
+        // Validate every texture before changing the target, so SDL is never handed a texture
+        // that can't be rendered to.
+        let textures: Vec<_> = textures.collect();
+        for (texture, _) in &textures {
+            check_render_target(texture.raw)?;
+        }
         for &(ref texture, ref user_context) in textures {
             unsafe { self.set_raw_target(texture.raw) }
                 .map_err(|e| TargetRenderError::SdlError(e))?;
@@ -797,6 +1908,121 @@ impl<T: RenderTarget> Canvas<T> {
             .map_err(|e| TargetRenderError::SdlError(e))?;
         Ok(())
     }
+
+    /// Sets the render target to a texture, or back to the window or surface the `Canvas` was
+    /// created from if `target` is `None`.
+    ///
+    /// Unlike [`Canvas::with_texture_canvas`] and [`Canvas::push_target`], the texture stays
+    /// the target until this is called again, which suits renderers structured as state
+    /// machines. Because the borrow of the texture ends when this returns, the compiler can't
+    /// check how the texture is used while it is the target; prefer `push_target`, whose guard
+    /// holds the borrow, when the target's lifetime fits in a scope.
+    ///
+    /// Errors with [`TargetRenderError::NotSupported`] if the texture was not created with
+    /// `TextureAccess::Target`, or with [`TargetRenderError::SdlError`] if SDL fails to set the
+    /// target.
+    ///
+    /// # Safety
+    ///
+    /// While the texture is the render target, until this is called again or
+    /// [`Canvas::reset_render_target`] is called:
+    ///
+    /// * the texture must not be drawn to the canvas, e.g. with [`Canvas::copy`]: reading from
+    ///   and rendering to the same texture at once is undefined behavior at the GPU level.
+    /// * the texture must not be dropped, so it outlives its use as the target.
+    #[doc(alias = "SDL_SetRenderTarget")]
+    pub unsafe fn set_render_target(
+        &mut self,
+        target: Option<&mut Texture>,
+    ) -> Result<(), TargetRenderError> {
+        match target {
+            Some(texture) => {
+                check_render_target(texture.raw)?;
+                self.set_raw_target(texture.raw)
+                    .map_err(TargetRenderError::SdlError)
+            }
+            None => self
+                .reset_render_target()
+                .map_err(TargetRenderError::SdlError),
+        }
+    }
+
+    /// Sets the render target back to the window or surface the `Canvas` was created from.
+    ///
+    /// This does nothing if the target already is the window or surface.
+    #[doc(alias = "SDL_SetRenderTarget")]
+    pub fn reset_render_target(&mut self) -> Result<(), Error> {
+        unsafe { self.set_raw_target(ptr::null_mut()) }
+    }
+
+    /// Sets the target of the `Canvas` to a `Texture` until the returned guard is dropped.
+    ///
+    /// This is the same as [`Canvas::with_texture_canvas`], but without a closure. The guard
+    /// derefs to the `Canvas`, so every drawing method can be called on it. When it is
+    /// dropped, the target that was active before this call is restored, so guards can be
+    /// nested.
+    ///
+    /// Errors with [`TargetRenderError::NotSupported`] if the texture was not created with
+    /// `TextureAccess::Target`, or with [`TargetRenderError::SdlError`] if SDL fails to set the
+    /// target.
+    ///
+    /// ```rust,no_run
+    /// # use sdl3::render::Canvas;
+    /// # use sdl3::video::Window;
+    /// # use sdl3::pixels::Color;
+    /// # let mut canvas : Canvas<Window> = unimplemented!();
+    /// let texture_creator = canvas.texture_creator();
+    /// let mut texture = texture_creator.create_texture_target(None, 150, 150).unwrap();
+    /// {
+    ///     let mut target = canvas.push_target(&mut texture).unwrap();
+    ///     target.set_draw_color(Color::RGB(255, 0, 0));
+    ///     target.clear();
+    /// }
+    /// // `canvas` draws to the window again
+    /// ```
+    #[doc(alias = "SDL_SetRenderTarget")]
+    pub fn push_target<'a>(
+        &'a mut self,
+        texture: &'a mut Texture,
+    ) -> Result<RenderTargetGuard<'a, T>, TargetRenderError> {
+        check_render_target(texture.raw)?;
+        let previous = unsafe { self.get_raw_target() };
+        unsafe { self.set_raw_target(texture.raw) }.map_err(TargetRenderError::SdlError)?;
+        Ok(RenderTargetGuard {
+            canvas: self,
+            previous,
+        })
+    }
+}
+
+/// Restores the previous render target of a `Canvas` when dropped.
+///
+/// Returned by [`Canvas::push_target`].
+pub struct RenderTargetGuard<'a, T: RenderTarget> {
+    canvas: &'a mut Canvas<T>,
+    previous: *mut sys::render::SDL_Texture,
+}
+
+impl<T: RenderTarget> Deref for RenderTargetGuard<'_, T> {
+    type Target = Canvas<T>;
+
+    fn deref(&self) -> &Canvas<T> {
+        self.canvas
+    }
+}
+
+impl<T: RenderTarget> DerefMut for RenderTargetGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Canvas<T> {
+        self.canvas
+    }
+}
+
+impl<T: RenderTarget> Drop for RenderTargetGuard<'_, T> {
+    fn drop(&mut self) {
+        // There is no way to report an error from here, and SDL only fails on an invalid
+        // renderer.
+        let _ = unsafe { self.canvas.set_raw_target(self.previous) };
+    }
 }
 
 /// Creates Textures that cannot outlive the creator
@@ -841,11 +2067,210 @@ pub fn create_renderer(
     }
 }
 
+/// Vertical sync setting of a renderer.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum VSync {
+    /// Present as soon as possible, which may cause tearing.
+    Disabled,
+    /// Present on every vertical refresh.
+    Enabled,
+    /// Present on every vertical refresh, unless a frame was late, in which case present
+    /// immediately. Not supported by every driver.
+    Adaptive,
+    /// Present on every `n`th vertical refresh.
+    Interval(i32),
+}
+
+impl From<VSync> for c_int {
+    fn from(vsync: VSync) -> c_int {
+        match vsync {
+            VSync::Disabled => sys::render::SDL_RENDERER_VSYNC_DISABLED,
+            VSync::Enabled => 1,
+            VSync::Adaptive => sys::render::SDL_RENDERER_VSYNC_ADAPTIVE,
+            VSync::Interval(n) => n,
+        }
+    }
+}
+
+impl From<c_int> for VSync {
+    fn from(n: c_int) -> VSync {
+        match n {
+            sys::render::SDL_RENDERER_VSYNC_DISABLED => VSync::Disabled,
+            1 => VSync::Enabled,
+            sys::render::SDL_RENDERER_VSYNC_ADAPTIVE => VSync::Adaptive,
+            n => VSync::Interval(n),
+        }
+    }
+}
+
+/// How the logical size set with [`Canvas::set_logical_size`] is mapped to the output.
+#[repr(i32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum LogicalPresentation {
+    /// There is no logical size in effect.
+    Disabled = sys::render::SDL_LOGICAL_PRESENTATION_DISABLED.0,
+    /// The rendered content is stretched to the output resolution.
+    Stretch = sys::render::SDL_LOGICAL_PRESENTATION_STRETCH.0,
+    /// The rendered content is fit to the largest dimension and the other dimension is
+    /// letterboxed with black bars.
+    Letterbox = sys::render::SDL_LOGICAL_PRESENTATION_LETTERBOX.0,
+    /// The rendered content is fit to the smallest dimension and the other dimension extends
+    /// beyond the output bounds.
+    Overscan = sys::render::SDL_LOGICAL_PRESENTATION_OVERSCAN.0,
+    /// The rendered content is scaled up by integer multiples to fit the output resolution.
+    IntegerScale = sys::render::SDL_LOGICAL_PRESENTATION_INTEGER_SCALE.0,
+}
+
+impl From<LogicalPresentation> for sys::render::SDL_RendererLogicalPresentation {
+    fn from(mode: LogicalPresentation) -> sys::render::SDL_RendererLogicalPresentation {
+        sys::render::SDL_RendererLogicalPresentation(mode as i32)
+    }
+}
+
+impl From<sys::render::SDL_RendererLogicalPresentation> for LogicalPresentation {
+    fn from(mode: sys::render::SDL_RendererLogicalPresentation) -> LogicalPresentation {
+        match mode {
+            sys::render::SDL_LOGICAL_PRESENTATION_STRETCH => LogicalPresentation::Stretch,
+            sys::render::SDL_LOGICAL_PRESENTATION_LETTERBOX => LogicalPresentation::Letterbox,
+            sys::render::SDL_LOGICAL_PRESENTATION_OVERSCAN => LogicalPresentation::Overscan,
+            sys::render::SDL_LOGICAL_PRESENTATION_INTEGER_SCALE => {
+                LogicalPresentation::IntegerScale
+            }
+            // DISABLED, and values SDL never returns
+            _ => LogicalPresentation::Disabled,
+        }
+    }
+}
+
+/// Builds a `WindowCanvas` with options that have to be chosen when the renderer is created.
+///
+/// Returned by [`Window::into_canvas_builder`](crate::video::Window::into_canvas_builder).
+///
+/// ```rust,no_run
+/// # use sdl3::render::VSync;
+/// # let sdl_context = sdl3::init().unwrap();
+/// # let video_subsystem = sdl_context.video().unwrap();
+/// let window = video_subsystem.window("Example", 800, 600).build().unwrap();
+/// let canvas = window
+///     .into_canvas_builder()
+///     .driver("software")
+///     .vsync(VSync::Enabled)
+///     .build()
+///     .unwrap();
+/// ```
+pub struct RendererBuilder {
+    window: Window,
+    driver: Option<String>,
+    vsync: Option<VSync>,
+    output_colorspace: Option<pixels::Colorspace>,
+}
+
+impl RendererBuilder {
+    /// Initializes a new `RendererBuilder` for the given window.
+    pub fn new(window: Window) -> RendererBuilder {
+        RendererBuilder {
+            window,
+            driver: None,
+            vsync: None,
+            output_colorspace: None,
+        }
+    }
+
+    /// Requests a specific render driver, e.g. `"opengl"` or `"software"`.
+    ///
+    /// If this is not set, SDL picks the best driver available.
+    pub fn driver(mut self, name: &str) -> RendererBuilder {
+        self.driver = Some(name.to_owned());
+        self
+    }
+
+    /// Sets the vertical sync of the renderer.
+    pub fn vsync(mut self, vsync: VSync) -> RendererBuilder {
+        self.vsync = Some(vsync);
+        self
+    }
+
+    /// Shorthand for `vsync(VSync::Enabled)`.
+    pub fn present_vsync(self) -> RendererBuilder {
+        self.vsync(VSync::Enabled)
+    }
+
+    /// Sets the colorspace the renderer outputs in, e.g. `Colorspace::SrgbLinear` or
+    /// `Colorspace::Hdr10` for HDR output. Defaults to `Colorspace::Srgb`.
+    ///
+    /// Drivers only support some colorspaces; building fails if the colorspace isn't supported.
+    pub fn output_colorspace(mut self, colorspace: pixels::Colorspace) -> RendererBuilder {
+        self.output_colorspace = Some(colorspace);
+        self
+    }
+
+    /// Creates the renderer and returns the resulting canvas.
+    ///
+    /// The window is consumed even if this fails.
+    #[doc(alias = "SDL_CreateRendererWithProperties")]
+    pub fn build(self) -> Result<WindowCanvas, Error> {
+        let props = unsafe { sys::properties::SDL_CreateProperties() };
+        if props == 0 {
+            return Err(get_error());
+        }
+        let result = unsafe { self.build_with_properties(props) };
+        unsafe { sys::properties::SDL_DestroyProperties(props) };
+        result
+    }
+
+    unsafe fn build_with_properties(self, props: SDL_PropertiesID) -> Result<WindowCanvas, Error> {
+        if !sys::properties::SDL_SetPointerProperty(
+            props,
+            sys::render::SDL_PROP_RENDERER_CREATE_WINDOW_POINTER,
+            self.window.raw() as *mut libc::c_void,
+        ) {
+            return Err(get_error());
+        }
+        if let Some(driver) = self.driver {
+            let driver = CString::new(driver).map_err(|e| Error(e.to_string()))?;
+            if !sys::properties::SDL_SetStringProperty(
+                props,
+                sys::render::SDL_PROP_RENDERER_CREATE_NAME_STRING,
+                driver.as_ptr(),
+            ) {
+                return Err(get_error());
+            }
+        }
+        if let Some(vsync) = self.vsync {
+            if !sys::properties::SDL_SetNumberProperty(
+                props,
+                sys::render::SDL_PROP_RENDERER_CREATE_PRESENT_VSYNC_NUMBER,
+                c_int::from(vsync) as i64,
+            ) {
+                return Err(get_error());
+            }
+        }
+        if let Some(colorspace) = self.output_colorspace {
+            if !sys::properties::SDL_SetNumberProperty(
+                props,
+                sys::render::SDL_PROP_RENDERER_CREATE_OUTPUT_COLORSPACE_NUMBER,
+                colorspace as u32 as i64,
+            ) {
+                return Err(get_error());
+            }
+        }
+
+        let raw = sys::render::SDL_CreateRendererWithProperties(props);
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Canvas::from_window_and_renderer(self.window, raw))
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum TextureValueError {
     WidthOverflows(u32),
     HeightOverflows(u32),
     WidthMustBeMultipleOfTwoForFormat(u32, PixelFormat),
+    /// The texture was created, but filling it with its initial pixels failed.
+    UpdateFailed(UpdateTextureError),
     SdlError(Error),
 }
 
@@ -863,6 +2288,7 @@ impl fmt::Display for TextureValueError {
                     format, value
                 )
             }
+            UpdateFailed(ref e) => write!(f, "Texture update failed: {}", e),
             SdlError(ref e) => write!(f, "SDL error: {}", e),
         }
     }
@@ -876,19 +2302,17 @@ impl error::Error for TextureValueError {
             WidthOverflows(_) => "texture width overflow",
             HeightOverflows(_) => "texture height overflow",
             WidthMustBeMultipleOfTwoForFormat(..) => "texture width must be multiple of two",
+            UpdateFailed(_) => "texture update failed",
             SdlError(ref e) => &e.0,
         }
     }
 }
 
-#[doc(alias = "SDL_CreateTexture")]
-fn ll_create_texture(
-    context: *mut sys::render::SDL_Renderer,
+fn ll_validate_texture_size(
     pixel_format: PixelFormat,
-    access: TextureAccess,
     width: u32,
     height: u32,
-) -> Result<*mut sys::render::SDL_Texture, TextureValueError> {
+) -> Result<(c_int, c_int), TextureValueError> {
     use self::TextureValueError::*;
     let w = match validate_int(width, "width") {
         Ok(w) => w,
@@ -912,6 +2336,19 @@ fn ll_create_texture(
         };
     }
 
+    Ok((w, h))
+}
+
+#[doc(alias = "SDL_CreateTexture")]
+fn ll_create_texture(
+    context: *mut sys::render::SDL_Renderer,
+    pixel_format: PixelFormat,
+    access: TextureAccess,
+    width: u32,
+    height: u32,
+) -> Result<*mut sys::render::SDL_Texture, TextureValueError> {
+    let (w, h) = ll_validate_texture_size(pixel_format, width, height)?;
+
     Ok(
         unsafe {
             sys::render::SDL_CreateTexture(context, pixel_format.into(), access.into(), w, h)
@@ -919,13 +2356,203 @@ fn ll_create_texture(
     )
 }
 
-#[repr(i32)]
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub enum ScaleMode {
+/// Options for [`TextureCreator::create_texture_with_properties`].
+///
+/// Some texture options, like the colorspace or HDR parameters, can only be chosen when the
+/// texture is created.
+///
+/// ```rust,no_run
+/// # use sdl3::render::{Canvas, TextureAccess, TextureProperties};
+/// # use sdl3::video::Window;
+/// # let canvas : Canvas<Window> = unimplemented!();
+/// let texture_creator = canvas.texture_creator();
+/// let props = TextureProperties::new(256, 256)
+///     .access(TextureAccess::Streaming)
+///     .sdr_white_point(1.0);
+/// let texture = texture_creator.create_texture_with_properties(&props).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextureProperties {
+    format: Option<PixelFormat>,
+    access: TextureAccess,
+    width: u32,
+    height: u32,
+    colorspace: Option<pixels::Colorspace>,
+    sdr_white_point: Option<f32>,
+    hdr_headroom: Option<f32>,
+}
+
+impl TextureProperties {
+    /// Initializes texture options for a static texture of the given size, in the
+    /// `TextureCreator`'s default pixel format.
+    pub fn new(width: u32, height: u32) -> TextureProperties {
+        TextureProperties {
+            format: None,
+            access: TextureAccess::Static,
+            width,
+            height,
+            colorspace: None,
+            sdr_white_point: None,
+            hdr_headroom: None,
+        }
+    }
+
+    /// Sets the pixel format of the texture.
+    pub fn format(mut self, format: PixelFormat) -> TextureProperties {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the access of the texture.
+    pub fn access(mut self, access: TextureAccess) -> TextureProperties {
+        self.access = access;
+        self
+    }
+
+    /// Sets the width of the texture in pixels.
+    pub fn width(mut self, width: u32) -> TextureProperties {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the texture in pixels.
+    pub fn height(mut self, height: u32) -> TextureProperties {
+        self.height = height;
+        self
+    }
+
+    /// Sets the colorspace of the texture. Defaults to sRGB for RGB formats and the JPEG
+    /// colorspace for YUV formats.
+    pub fn colorspace<C: Into<pixels::Colorspace>>(mut self, colorspace: C) -> TextureProperties {
+        self.colorspace = Some(colorspace.into());
+        self
+    }
+
+    /// Sets the value of SDR white in the linear colorspace, for HDR textures.
+    pub fn sdr_white_point(mut self, sdr_white_point: f32) -> TextureProperties {
+        self.sdr_white_point = Some(sdr_white_point);
+        self
+    }
+
+    /// Sets the maximum dynamic range of the texture, in terms of the SDR white point, for HDR
+    /// textures.
+    pub fn hdr_headroom(mut self, hdr_headroom: f32) -> TextureProperties {
+        self.hdr_headroom = Some(hdr_headroom);
+        self
+    }
+}
+
+/// Builder for a `Texture`, created with [`TextureCreator::texture`].
+///
+/// The format defaults to the `TextureCreator`'s default format and the access to
+/// `TextureAccess::Static`. The size must be set with [`TextureBuilder::size`].
+///
+/// ```rust,no_run
+/// # use sdl3::render::{BlendMode, Canvas, ScaleMode, TextureAccess};
+/// # use sdl3::video::Window;
+/// # let canvas : Canvas<Window> = unimplemented!();
+/// let texture_creator = canvas.texture_creator();
+/// let texture = texture_creator
+///     .texture()
+///     .size(320, 180)
+///     .access(TextureAccess::Target)
+///     .scale_mode(ScaleMode::Nearest)
+///     .blend_mode(BlendMode::Blend)
+///     .build()
+///     .unwrap();
+/// ```
+pub struct TextureBuilder<'a, T> {
+    creator: &'a TextureCreator<T>,
+    format: Option<PixelFormat>,
+    access: TextureAccess,
+    width: u32,
+    height: u32,
+    scale_mode: Option<ScaleMode>,
+    blend_mode: Option<BlendMode>,
+}
+
+impl<'a, T> TextureBuilder<'a, T> {
+    pub fn format(mut self, format: PixelFormat) -> TextureBuilder<'a, T> {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn access(mut self, access: TextureAccess) -> TextureBuilder<'a, T> {
+        self.access = access;
+        self
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> TextureBuilder<'a, T> {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Sets the scale mode of the texture once it's created.
+    pub fn scale_mode(mut self, scale_mode: ScaleMode) -> TextureBuilder<'a, T> {
+        self.scale_mode = Some(scale_mode);
+        self
+    }
+
+    /// Sets the blend mode of the texture once it's created.
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> TextureBuilder<'a, T> {
+        self.blend_mode = Some(blend_mode);
+        self
+    }
+
+    /// Creates the texture and applies the scale and blend modes.
+    #[cfg(not(feature = "unsafe_textures"))]
+    pub fn build(self) -> Result<Texture<'a>, TextureValueError> {
+        let texture =
+            self.creator
+                .create_texture(self.format, self.access, self.width, self.height)?;
+        self.apply_modes(texture.raw)?;
+        Ok(texture)
+    }
+
+    /// Creates the texture and applies the scale and blend modes.
+    #[cfg(feature = "unsafe_textures")]
+    pub fn build(self) -> Result<Texture, TextureValueError> {
+        let texture =
+            self.creator
+                .create_texture(self.format, self.access, self.width, self.height)?;
+        if let Err(e) = self.apply_modes(texture.raw) {
+            unsafe { texture.destroy() };
+            return Err(e);
+        }
+        Ok(texture)
+    }
+
+    fn apply_modes(&self, raw: *mut sys::render::SDL_Texture) -> Result<(), TextureValueError> {
+        if let Some(scale_mode) = self.scale_mode {
+            if !unsafe { sys::render::SDL_SetTextureScaleMode(raw, scale_mode.into()) } {
+                return Err(TextureValueError::SdlError(get_error()));
+            }
+        }
+        if let Some(blend_mode) = self.blend_mode {
+            if !unsafe { sys::render::SDL_SetTextureBlendMode(raw, blend_mode.to_ll()) } {
+                return Err(TextureValueError::SdlError(get_error()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `SDL_SCALEMODE_PIXELART`, added in SDL 3.4 and not yet part of the bindings.
+const SDL_SCALEMODE_PIXELART: sdl3_sys::everything::SDL_ScaleMode =
+    sdl3_sys::everything::SDL_ScaleMode(2);
+
+#[repr(i32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ScaleMode {
     /// nearest pixel sampling.
     Nearest = sdl3_sys::everything::SDL_ScaleMode::NEAREST.0,
     /// linear filtering. this is the default
     Linear = sdl3_sys::everything::SDL_ScaleMode::LINEAR.0,
+    /// nearest pixel sampling with improved scaling for pixel art: crisp at integer scales,
+    /// antialiased at non-integer ones. Requires SDL 3.4 or newer; older versions of SDL
+    /// reject it.
+    PixelArt = SDL_SCALEMODE_PIXELART.0,
 }
 
 impl Into<sdl3_sys::everything::SDL_ScaleMode> for ScaleMode {
@@ -933,6 +2560,7 @@ impl Into<sdl3_sys::everything::SDL_ScaleMode> for ScaleMode {
         match self {
             ScaleMode::Nearest => sdl3_sys::everything::SDL_ScaleMode::NEAREST,
             ScaleMode::Linear => sdl3_sys::everything::SDL_ScaleMode::LINEAR,
+            ScaleMode::PixelArt => SDL_SCALEMODE_PIXELART,
         }
     }
 }
@@ -944,6 +2572,7 @@ impl TryFrom<sdl3_sys::everything::SDL_ScaleMode> for ScaleMode {
         Ok(match n {
             sdl3_sys::everything::SDL_ScaleMode::NEAREST => Self::Nearest,
             sdl3_sys::everything::SDL_ScaleMode::LINEAR => Self::Linear,
+            SDL_SCALEMODE_PIXELART => Self::PixelArt,
             _ => return Err(()),
         })
     }
@@ -962,6 +2591,20 @@ impl<T> TextureCreator<T> {
         self.default_pixel_format
     }
 
+    /// Starts building a texture, as an alternative to the `create_texture*` methods. See
+    /// [`TextureBuilder`].
+    pub fn texture(&self) -> TextureBuilder<'_, T> {
+        TextureBuilder {
+            creator: self,
+            format: None,
+            access: TextureAccess::Static,
+            width: 0,
+            height: 0,
+            scale_mode: None,
+            blend_mode: None,
+        }
+    }
+
     /// Creates a texture for a rendering context.
     ///
     /// If format is `None`, the format will be the one the parent Window or Surface uses.
@@ -1035,6 +2678,82 @@ impl<T> TextureCreator<T> {
         self.create_texture(format, TextureAccess::Target, width, height)
     }
 
+    /// Creates a texture with options that can only be set at creation time, such as its
+    /// colorspace. See [`TextureProperties`].
+    ///
+    /// If no format was set in `props`, the format will be the one the parent Window or Surface
+    /// uses.
+    #[doc(alias = "SDL_CreateTextureWithProperties")]
+    pub fn create_texture_with_properties(
+        &self,
+        props: &TextureProperties,
+    ) -> Result<Texture, TextureValueError> {
+        use self::TextureValueError::*;
+        let format = props.format.unwrap_or(self.default_pixel_format);
+        let (w, h) = ll_validate_texture_size(format, props.width, props.height)?;
+
+        let raw_props = unsafe { sys::properties::SDL_CreateProperties() };
+        if raw_props == 0 {
+            return Err(SdlError(get_error()));
+        }
+        let result = unsafe {
+            let mut ok = sys::properties::SDL_SetNumberProperty(
+                raw_props,
+                sys::render::SDL_PROP_TEXTURE_CREATE_FORMAT_NUMBER,
+                format.raw().0 as i64,
+            ) && sys::properties::SDL_SetNumberProperty(
+                raw_props,
+                sys::render::SDL_PROP_TEXTURE_CREATE_ACCESS_NUMBER,
+                SDL_TextureAccess::from(props.access).0 as i64,
+            ) && sys::properties::SDL_SetNumberProperty(
+                raw_props,
+                sys::render::SDL_PROP_TEXTURE_CREATE_WIDTH_NUMBER,
+                w as i64,
+            ) && sys::properties::SDL_SetNumberProperty(
+                raw_props,
+                sys::render::SDL_PROP_TEXTURE_CREATE_HEIGHT_NUMBER,
+                h as i64,
+            );
+            if let Some(colorspace) = props.colorspace {
+                ok = ok
+                    && sys::properties::SDL_SetNumberProperty(
+                        raw_props,
+                        sys::render::SDL_PROP_TEXTURE_CREATE_COLORSPACE_NUMBER,
+                        colorspace as u32 as i64,
+                    );
+            }
+            if let Some(sdr_white_point) = props.sdr_white_point {
+                ok = ok
+                    && sys::properties::SDL_SetFloatProperty(
+                        raw_props,
+                        sys::render::SDL_PROP_TEXTURE_CREATE_SDR_WHITE_POINT_FLOAT,
+                        sdr_white_point,
+                    );
+            }
+            if let Some(hdr_headroom) = props.hdr_headroom {
+                ok = ok
+                    && sys::properties::SDL_SetFloatProperty(
+                        raw_props,
+                        sys::render::SDL_PROP_TEXTURE_CREATE_HDR_HEADROOM_FLOAT,
+                        hdr_headroom,
+                    );
+            }
+            if ok {
+                sys::render::SDL_CreateTextureWithProperties(self.context.raw, raw_props)
+            } else {
+                ptr::null_mut()
+            }
+        };
+        // grab the error before destroying the properties, which may overwrite it
+        let result = if result.is_null() {
+            Err(SdlError(get_error()))
+        } else {
+            unsafe { Ok(self.raw_create_texture(result)) }
+        };
+        unsafe { sys::properties::SDL_DestroyProperties(raw_props) };
+        result
+    }
+
     /// Creates a texture from an existing surface.
     ///
     /// # Remarks
@@ -1079,6 +2798,61 @@ impl<T> TextureCreator<T> {
         }
     }
 
+    /// Creates a texture with the given access from an existing surface, e.g. to seed a
+    /// streaming texture with an initial image.
+    ///
+    /// The texture uses the surface's pixel format if the renderer supports it, and otherwise
+    /// a supported format with an alpha channel if the surface has one. Like
+    /// [`TextureCreator::create_texture_from_surface`], the blend mode is set to
+    /// `BlendMode::Blend` if the surface has an alpha channel.
+    ///
+    /// Errors with [`TextureValueError::UpdateFailed`] if the surface's pixels can't be copied
+    /// into the texture, in which case the texture is destroyed.
+    #[doc(alias = "SDL_UpdateTexture")]
+    pub fn create_texture_from_surface_with_access<S: AsRef<SurfaceRef>>(
+        &self,
+        surface: S,
+        access: TextureAccess,
+    ) -> Result<Texture, TextureValueError> {
+        use self::TextureValueError::*;
+        let surface = surface.as_ref();
+        let surface_format = surface.pixel_format();
+        let has_alpha = surface_format.supports_alpha();
+        let supported = self.context.get_texture_formats();
+        let format = if supported.contains(&surface_format) {
+            surface_format
+        } else {
+            supported
+                .iter()
+                .copied()
+                .find(|format| format.supports_alpha() == has_alpha)
+                .unwrap_or(self.default_pixel_format)
+        };
+
+        let mut texture = self.create_texture(format, access, surface.width(), surface.height())?;
+        let result = match texture.update_from_surface(None, surface) {
+            Err(e) => Err(UpdateFailed(e)),
+            Ok(()) if has_alpha => {
+                let blend = BlendMode::Blend.to_ll();
+                if unsafe { sys::render::SDL_SetTextureBlendMode(texture.raw, blend) } {
+                    Ok(())
+                } else {
+                    Err(SdlError(get_error()))
+                }
+            }
+            Ok(()) => Ok(()),
+        };
+        if let Err(e) = result {
+            // Textures aren't destroyed on drop with `unsafe_textures`.
+            #[cfg(feature = "unsafe_textures")]
+            unsafe {
+                texture.destroy()
+            };
+            return Err(e);
+        }
+        Ok(texture)
+    }
+
     /// Create a texture from its raw `SDL_Texture`.
     #[cfg(not(feature = "unsafe_textures"))]
     #[inline]
@@ -1102,17 +2876,160 @@ impl<T: RenderTarget> Canvas<T> {
     // https://github.com/rust-lang/rust-clippy/issues/5953 is fixed
     #[allow(clippy::trivially_copy_pass_by_ref)]
     pub fn raw(&self) -> *mut sys::render::SDL_Renderer {
-        self.context.raw()
+        self.context.checked_raw()
+    }
+
+    /// Returns whether the canvas is currently rendering to its window or surface, or to a
+    /// texture.
+    #[doc(alias = "SDL_GetRenderTarget")]
+    pub fn current_target(&self) -> RenderTargetKind {
+        if !unsafe { self.context.get_raw_target() }.is_null() {
+            RenderTargetKind::Texture
+        } else if self.context.window_id().is_some() {
+            RenderTargetKind::Window
+        } else {
+            RenderTargetKind::Surface
+        }
+    }
+
+    /// Gets the size of the texture currently being rendered to.
+    ///
+    /// Returns `None` when rendering to the window or surface the canvas was created from,
+    /// whose size is given by [`Canvas::output_size`].
+    #[doc(alias = "SDL_GetRenderTarget")]
+    pub fn render_target_dimensions(&self) -> Option<(u32, u32)> {
+        let raw = unsafe { self.context.get_raw_target() };
+        if raw.is_null() {
+            return None;
+        }
+        let texture = InternalTexture { raw };
+        Some((texture.get_width(), texture.get_height()))
+    }
+
+    /// Gets the name of the rendering driver, e.g. `"opengl"` or `"software"`.
+    #[inline]
+    #[doc(alias = "SDL_GetRendererName")]
+    pub fn name(&self) -> &str {
+        &self.renderer_name
+    }
+
+    /// Gets the name of the driver the renderer actually uses, as reported by SDL.
+    ///
+    /// The requested driver is only a preference: with [`RendererBuilder::driver`] or the
+    /// `SDL_HINT_RENDER_DRIVER` hint set to a list such as `"opengl,software"`, SDL picks the
+    /// first one that works, and without a preference it picks whatever suits the platform.
+    /// Compare against this to find out which driver you actually got.
+    #[inline]
+    #[doc(alias = "SDL_GetRendererName")]
+    pub fn actual_driver(&self) -> &str {
+        &self.renderer_name
+    }
+
+    /// Checks whether the renderer can still be used.
+    ///
+    /// Returns `false` once SDL has reported that the render device was lost, see
+    /// [`Event::RenderDeviceLost`](crate::event::Event::RenderDeviceLost). From then on every
+    /// draw call and `present` fails, and the `Canvas`, its `TextureCreator`s and all of their
+    /// `Texture`s must be dropped and created again. Checking this after `present` returns an
+    /// error tells a lost device apart from other failures.
+    ///
+    /// Only the renderer of the window named by the event is affected. A `Canvas` created from
+    /// a `Surface` has no device to lose.
+    #[doc(alias = "SDL_EVENT_RENDER_DEVICE_LOST")]
+    pub fn is_valid(&self) -> bool {
+        let lost = self
+            .context
+            .device_lost
+            .as_ref()
+            .is_some_and(|watch| watch.lost.load(Ordering::Relaxed));
+        !lost && !unsafe { sys::render::SDL_GetRendererName(self.context.checked_raw()) }.is_null()
+    }
+
+    /// Gets the maximum width and height of a texture.
+    #[doc(alias = "SDL_PROP_RENDERER_MAX_TEXTURE_SIZE_NUMBER")]
+    pub fn max_texture_size(&self) -> u32 {
+        self.context
+            .get_number_property(sys::render::SDL_PROP_RENDERER_MAX_TEXTURE_SIZE_NUMBER)
+            .unwrap_or(0) as u32
+    }
+
+    /// Gets the texture formats supported by the renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_TEXTURE_FORMATS_POINTER")]
+    pub fn supported_texture_formats(&self) -> Vec<PixelFormat> {
+        self.context.get_texture_formats()
+    }
+
+    /// Gets the properties of the renderer.
+    #[doc(alias = "SDL_GetRendererProperties")]
+    pub fn get_properties(&self) -> RendererProperties {
+        let props = self.context.get_raw_properties();
+        unsafe {
+            let name = sys::properties::SDL_GetStringProperty(
+                props,
+                sys::render::SDL_PROP_RENDERER_NAME_STRING,
+                ptr::null(),
+            );
+            let name = if name.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(name).to_string_lossy().into_owned()
+            };
+            RendererProperties {
+                name,
+                max_texture_size: sys::properties::SDL_GetNumberProperty(
+                    props,
+                    sys::render::SDL_PROP_RENDERER_MAX_TEXTURE_SIZE_NUMBER,
+                    0,
+                ) as u32,
+                texture_formats: self.context.get_texture_formats(),
+                output_colorspace: sys::pixels::SDL_Colorspace(
+                    sys::properties::SDL_GetNumberProperty(
+                        props,
+                        sys::render::SDL_PROP_RENDERER_OUTPUT_COLORSPACE_NUMBER,
+                        sys::pixels::SDL_COLORSPACE_SRGB.0 as i64,
+                    ) as u32,
+                )
+                .into(),
+                hdr_enabled: sys::properties::SDL_GetBooleanProperty(
+                    props,
+                    sys::render::SDL_PROP_RENDERER_HDR_ENABLED_BOOLEAN,
+                    false,
+                ),
+                sdr_white_point: sys::properties::SDL_GetFloatProperty(
+                    props,
+                    sys::render::SDL_PROP_RENDERER_SDR_WHITE_POINT_FLOAT,
+                    1.0,
+                ),
+                hdr_headroom: sys::properties::SDL_GetFloatProperty(
+                    props,
+                    sys::render::SDL_PROP_RENDERER_HDR_HEADROOM_FLOAT,
+                    1.0,
+                ),
+            }
+        }
     }
 
     /// Sets the color used for drawing operations (Rect, Line and Clear).
+    ///
+    /// Panics if SDL fails to set the color; see [`Canvas::try_set_draw_color`].
     #[doc(alias = "SDL_SetRenderDrawColor")]
     pub fn set_draw_color<C: Into<pixels::Color>>(&mut self, color: C) {
+        self.try_set_draw_color(color)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Sets the color used for drawing operations (Rect, Line and Clear).
+    ///
+    /// Errors if SDL fails to set the color, e.g. because the renderer is invalid.
+    #[doc(alias = "SDL_SetRenderDrawColor")]
+    pub fn try_set_draw_color<C: Into<pixels::Color>>(&mut self, color: C) -> Result<(), Error> {
         let (r, g, b, a) = color.into().rgba();
-        let ret = unsafe { sys::render::SDL_SetRenderDrawColor(self.raw, r, g, b, a) };
-        // Should only fail on an invalid renderer
+        let ret =
+            unsafe { sys::render::SDL_SetRenderDrawColor(self.context.checked_raw(), r, g, b, a) };
         if !ret {
-            panic!("{}", get_error())
+            Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
@@ -1121,7 +3038,13 @@ impl<T: RenderTarget> Canvas<T> {
     pub fn draw_color(&self) -> pixels::Color {
         let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
         let ret = unsafe {
-            sys::render::SDL_GetRenderDrawColor(self.context.raw, &mut r, &mut g, &mut b, &mut a)
+            sys::render::SDL_GetRenderDrawColor(
+                self.context.checked_raw(),
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )
         };
         // Should only fail on an invalid renderer
         if !ret {
@@ -1131,15 +3054,99 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Sets the color used for drawing operations, with floating point precision.
+    ///
+    /// Channels may exceed 1.0, which is useful for HDR rendering together with
+    /// [`Canvas::set_color_scale`].
+    #[doc(alias = "SDL_SetRenderDrawColorFloat")]
+    pub fn set_draw_color_float<C: Into<pixels::FColor>>(&mut self, color: C) {
+        let color = color.into();
+        let ret = unsafe {
+            sys::render::SDL_SetRenderDrawColorFloat(
+                self.context.checked_raw(),
+                color.r,
+                color.g,
+                color.b,
+                color.a,
+            )
+        };
+        if !ret {
+            panic!("{}", get_error())
+        }
+    }
+
+    /// Gets the color used for drawing operations, with floating point precision.
+    #[doc(alias = "SDL_GetRenderDrawColorFloat")]
+    pub fn draw_color_float(&self) -> pixels::FColor {
+        // Should only fail on an invalid renderer
+        self.try_draw_color_float()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Gets the color used for drawing operations, for draw calls that report a lost or
+    /// invalid renderer as an error rather than panicking.
+    fn try_draw_color_float(&self) -> Result<pixels::FColor, Error> {
+        let (mut r, mut g, mut b, mut a) = (0.0, 0.0, 0.0, 0.0);
+        let ret = unsafe {
+            sys::render::SDL_GetRenderDrawColorFloat(
+                self.context.checked_raw(),
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )
+        };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(pixels::FColor::RGBA(r, g, b, a))
+        }
+    }
+
     /// Sets the blend mode used for drawing operations (Fill and Line).
+    ///
+    /// Panics if SDL fails to set the blend mode; see [`Canvas::try_set_blend_mode`].
     #[doc(alias = "SDL_SetRenderDrawBlendMode")]
     pub fn set_blend_mode(&mut self, blend: BlendMode) {
-        let ret =
-            unsafe { sys::render::SDL_SetRenderDrawBlendMode(self.context.raw, blend as u32) };
-        // Should only fail on an invalid renderer
+        self.try_set_blend_mode(blend)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Sets the blend mode used for drawing operations (Fill and Line).
+    ///
+    /// Errors if SDL fails to set the blend mode, e.g. because the renderer is invalid, or if
+    /// the renderer does not support it. See [`Canvas::supports_blend_mode`].
+    #[doc(alias = "SDL_SetRenderDrawBlendMode")]
+    pub fn try_set_blend_mode(&mut self, blend: BlendMode) -> Result<(), Error> {
+        self.context.set_draw_blend_mode(blend.to_ll())
+    }
+
+    /// Sets a custom blend mode used for drawing operations (Fill and Line).
+    ///
+    /// Errors if the renderer does not support the given blend mode.
+    #[doc(alias = "SDL_SetRenderDrawBlendMode")]
+    pub fn set_custom_blend_mode(&mut self, blend: CustomBlendMode) -> Result<(), Error> {
+        self.context.set_draw_blend_mode(blend.to_ll())
+    }
+
+    /// Checks whether the renderer supports drawing with `mode`, e.g. to pick a fallback at
+    /// startup for backends that can't do `BlendMode::Mul` or a custom blend mode.
+    ///
+    /// This sets the mode and reads it back, then restores the previous blend mode. Returns
+    /// `false` if the previous blend mode can't be read, e.g. on an invalid renderer.
+    #[doc(alias = "SDL_SetRenderDrawBlendMode")]
+    pub fn supports_blend_mode<B: Into<BlendMode>>(&mut self, mode: B) -> bool {
+        let mut previous: SDL_BlendMode = 0;
+        let ret = unsafe {
+            sys::render::SDL_GetRenderDrawBlendMode(self.context.checked_raw(), &mut previous)
+        };
         if !ret {
-            panic!("{}", get_error())
+            return false;
         }
+        let _restore = RestoreRenderState::BlendMode(self.context.checked_raw(), previous);
+        self.context
+            .set_draw_blend_mode(mode.into().to_ll())
+            .is_ok()
     }
 
     /// Gets the blend mode used for drawing operations.
@@ -1147,26 +3154,141 @@ impl<T: RenderTarget> Canvas<T> {
     pub fn blend_mode(&self) -> BlendMode {
         let mut blend: MaybeUninit<SDL_BlendMode> = mem::MaybeUninit::uninit();
         let ret = unsafe {
-            sys::render::SDL_GetRenderDrawBlendMode(self.context.raw, blend.as_mut_ptr())
+            sys::render::SDL_GetRenderDrawBlendMode(self.context.checked_raw(), blend.as_mut_ptr())
         };
         // Should only fail on an invalid renderer
         if !ret {
             panic!("{}", get_error())
         } else {
             let blend = unsafe { blend.assume_init() };
-            BlendMode::try_from(blend).unwrap()
+            BlendMode::from(blend)
+        }
+    }
+
+    /// Sets the draw color while `f` runs, then restores the previous one, and returns what
+    /// `f` returned.
+    ///
+    /// The previous color is restored even if `f` panics, and keeps its full float precision.
+    ///
+    /// Errors without calling `f` if SDL fails to read the previous color or to set the new
+    /// one.
+    #[doc(alias = "SDL_SetRenderDrawColor")]
+    pub fn with_draw_color<C, F, R>(&mut self, color: C, f: F) -> Result<R, Error>
+    where
+        C: Into<pixels::Color>,
+        F: FnOnce(&mut Canvas<T>) -> R,
+    {
+        let _restore =
+            RestoreRenderState::DrawColor(self.context.checked_raw(), self.try_draw_color_float()?);
+        self.try_set_draw_color(color)?;
+        Ok(f(self))
+    }
+
+    /// Sets the blend mode used for drawing operations while `f` runs, then restores the
+    /// previous one, and returns what `f` returned.
+    ///
+    /// The previous blend mode is restored even if `f` panics, including custom blend modes.
+    ///
+    /// Errors without calling `f` if SDL fails to read the previous blend mode, or if the
+    /// renderer doesn't support `blend`.
+    #[doc(alias = "SDL_SetRenderDrawBlendMode")]
+    pub fn with_blend_mode<F, R>(&mut self, blend: BlendMode, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut Canvas<T>) -> R,
+    {
+        let mut previous: SDL_BlendMode = 0;
+        let ret = unsafe {
+            sys::render::SDL_GetRenderDrawBlendMode(self.context.checked_raw(), &mut previous)
+        };
+        if !ret {
+            return Err(get_error());
+        }
+        let _restore = RestoreRenderState::BlendMode(self.context.checked_raw(), previous);
+        self.try_set_blend_mode(blend)?;
+        Ok(f(self))
+    }
+
+    /// Enables or disables drawing on this canvas.
+    ///
+    /// While drawing is disabled, `clear`, `copy`, `fill_rect`, and the other draw calls
+    /// return immediately without calling into SDL. This is meant as an A/B switch when
+    /// profiling how much of a frame is spent submitting draw calls. `present` still runs, so
+    /// the timing of the loop (including vsync) is otherwise unchanged.
+    ///
+    /// Drawing is enabled by default.
+    pub fn set_draw_enabled(&mut self, enabled: bool) {
+        self.draw_enabled = enabled;
+    }
+
+    /// Returns whether drawing is enabled. See [`Canvas::set_draw_enabled`].
+    pub fn draw_enabled(&self) -> bool {
+        self.draw_enabled
+    }
+
+    /// Makes `copy` and `copy_ex` skip textures whose alpha modulation is 0.
+    ///
+    /// When enabled, every copy reads the texture's alpha mod first and returns `Ok(())`
+    /// without drawing if it is 0, which avoids submitting invisible sprites, e.g. at the end
+    /// of a fade-out. Reading the alpha mod has a small cost of its own, and with
+    /// `BlendMode::None` a fully transparent copy still overwrites the destination, so this is
+    /// disabled by default.
+    pub fn set_skip_transparent_copies(&mut self, skip: bool) {
+        self.skip_transparent_copies = skip;
+    }
+
+    /// Returns whether fully transparent copies are skipped. See
+    /// [`Canvas::set_skip_transparent_copies`].
+    pub fn skip_transparent_copies(&self) -> bool {
+        self.skip_transparent_copies
+    }
+
+    /// Returns whether a copy of `texture` should be skipped because it is fully transparent.
+    fn is_transparent_copy(&self, texture: &Texture) -> bool {
+        if !self.skip_transparent_copies {
+            return false;
         }
+        let mut alpha = 0;
+        let ret = unsafe { sys::render::SDL_GetTextureAlphaMod(texture.raw, &mut alpha) };
+        ret && alpha == 0
     }
 
     /// Clears the current rendering target with the drawing color.
+    ///
+    /// Panics if SDL fails to clear; see [`Canvas::try_clear`].
     #[doc(alias = "SDL_RenderClear")]
     pub fn clear(&mut self) {
-        let ret = unsafe { sys::render::SDL_RenderClear(self.context.raw) };
+        self.try_clear()
+            .unwrap_or_else(|e| panic!("Could not clear: {}", e))
+    }
+
+    /// Clears the current rendering target with the drawing color.
+    ///
+    /// Errors if SDL fails to clear, e.g. because the render device was lost.
+    #[doc(alias = "SDL_RenderClear")]
+    pub fn try_clear(&mut self) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
+        let ret = unsafe { sys::render::SDL_RenderClear(self.context.checked_raw()) };
         if !ret {
-            panic!("Could not clear: {}", get_error())
+            Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
+    /// Sets the draw color to `color`, then clears the current rendering target with it.
+    ///
+    /// The draw color stays set afterwards, like after [`Canvas::set_draw_color`].
+    ///
+    /// Errors if SDL fails to set the color or to clear, e.g. because the render device was
+    /// lost.
+    #[doc(alias = "SDL_RenderClear")]
+    pub fn clear_with<C: Into<pixels::Color>>(&mut self, color: C) -> Result<(), Error> {
+        self.try_set_draw_color(color)?;
+        self.try_clear()
+    }
+
     /// Updates the screen with any rendering performed since the previous call.
     ///
     /// SDL's rendering functions operate on a backbuffer; that is, calling a
@@ -1175,42 +3297,141 @@ impl<T: RenderTarget> Canvas<T> {
     /// As such, you compose your entire scene and present the composed
     /// backbuffer to the screen as a complete picture.
     ///
-    /// Returns `true` on success, or `false` on error. Call `get_error()` for more information.
+    /// Errors if presenting failed, e.g. because the render device was lost.
     #[doc(alias = "SDL_RenderPresent")]
-    pub fn present(&mut self) -> bool {
-        unsafe { sys::render::SDL_RenderPresent(self.context.raw) }
-    }
-
-    /// Gets the output size of a rendering context.
-    #[doc(alias = "SDL_GetCurrentRenderOutputSize")]
-    pub fn output_size(&self) -> Result<(u32, u32), Error> {
-        let mut width = 0;
-        let mut height = 0;
-
-        let result = unsafe {
-            sys::render::SDL_GetCurrentRenderOutputSize(self.context.raw, &mut width, &mut height)
-        };
-
-        if result {
-            Ok((width as u32, height as u32))
-        } else {
+    pub fn present(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::render::SDL_RenderPresent(self.context.checked_raw()) };
+        if !ret {
             Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
-    /// Sets a device independent resolution for rendering.
-    #[doc(alias = "SDL_SetRenderLogicalPresentation")]
-    pub fn set_logical_size(
+    /// Presents, then sleeps until `1 / target_fps` seconds have passed since `last_present`,
+    /// to run at a fixed framerate without vsync.
+    ///
+    /// `last_present` is moved forward by exactly one frame, so small sleep inaccuracies don't
+    /// add up over time. If the application fell more than a frame behind, e.g. because of a
+    /// hitch, it is reset to the current time instead of trying to catch up. Initialize it with
+    /// `Instant::now()` before the first frame.
+    ///
+    /// Most of the wait uses `std::thread::sleep`; only the last couple of milliseconds are
+    /// spent spinning, since sleeping is often not precise enough for them.
+    ///
+    /// Errors if `target_fps` is not finite and positive, if the frame time or the next
+    /// deadline can't be represented (e.g. for a tiny `target_fps`), or if presenting failed.
+    #[doc(alias = "SDL_RenderPresent")]
+    pub fn present_at(&mut self, target_fps: f64, last_present: &mut Instant) -> Result<(), Error> {
+        if !(target_fps.is_finite() && target_fps > 0.0) {
+            return Err(Error(format!("invalid target framerate: {}", target_fps)));
+        }
+        let frame = Duration::try_from_secs_f64(1.0 / target_fps)
+            .map_err(|e| Error(format!("invalid target framerate {}: {}", target_fps, e)))?;
+        let deadline = last_present
+            .checked_add(frame)
+            .ok_or_else(|| Error("frame deadline is out of range".to_owned()))?;
+        self.present()?;
+
+        sleep_until(deadline);
+        let now = Instant::now();
+        *last_present = match deadline.checked_add(frame) {
+            Some(late) if now <= late => deadline,
+            _ => now,
+        };
+        Ok(())
+    }
+
+    /// Presents without waiting for vertical sync, even if vsync is enabled.
+    ///
+    /// Vsync is temporarily disabled for this one present and then restored to its previous
+    /// setting, which is useful for sections that should update as fast as possible, such as
+    /// a loading screen. Since the present is not synchronized with the display, the frame may
+    /// show visible tearing.
+    ///
+    /// Errors if the vsync setting could not be queried or changed, or if presenting failed.
+    #[doc(alias = "SDL_RenderPresent")]
+    pub fn present_immediate(&mut self) -> Result<(), Error> {
+        let vsync = self.vsync()?;
+        if vsync == VSync::Disabled {
+            return self.present();
+        }
+
+        self.set_vsync(VSync::Disabled)?;
+        let presented = self.present();
+        self.set_vsync(vsync)?;
+        presented
+    }
+
+    /// Sets the vertical sync mode used when presenting.
+    ///
+    /// Errors if the renderer does not support the requested mode.
+    #[doc(alias = "SDL_SetRenderVSync")]
+    pub fn set_vsync(&mut self, vsync: VSync) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::render::SDL_SetRenderVSync(self.context.checked_raw(), vsync.into()) };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Gets the vertical sync mode used when presenting.
+    #[doc(alias = "SDL_GetRenderVSync")]
+    pub fn vsync(&self) -> Result<VSync, Error> {
+        let mut vsync = 0;
+        let ret =
+            unsafe { sys::render::SDL_GetRenderVSync(self.context.checked_raw(), &mut vsync) };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(VSync::from(vsync))
+        }
+    }
+
+    /// Gets the output size of a rendering context.
+    #[doc(alias = "SDL_GetCurrentRenderOutputSize")]
+    pub fn output_size(&self) -> Result<(u32, u32), Error> {
+        let mut width = 0;
+        let mut height = 0;
+
+        let result = unsafe {
+            sys::render::SDL_GetCurrentRenderOutputSize(
+                self.context.checked_raw(),
+                &mut width,
+                &mut height,
+            )
+        };
+
+        if result {
+            Ok((width as u32, height as u32))
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Sets a device independent resolution for rendering.
+    ///
+    /// `mode` also accepts the raw `SDL_RendererLogicalPresentation` values for
+    /// compatibility, but [`LogicalPresentation`] should be preferred.
+    #[doc(alias = "SDL_SetRenderLogicalPresentation")]
+    pub fn set_logical_size<M: Into<LogicalPresentation>>(
         &mut self,
         width: u32,
         height: u32,
-        mode: sys::render::SDL_RendererLogicalPresentation,
+        mode: M,
     ) -> Result<(), IntegerOrSdlError> {
         use crate::common::IntegerOrSdlError::*;
         let width = validate_int(width, "width")?;
         let height = validate_int(height, "height")?;
         let result = unsafe {
-            sys::render::SDL_SetRenderLogicalPresentation(self.context.raw, width, height, mode)
+            sys::render::SDL_SetRenderLogicalPresentation(
+                self.context.checked_raw(),
+                width,
+                height,
+                mode.into().into(),
+            )
         };
         match result {
             true => Ok(()),
@@ -1220,7 +3441,19 @@ impl<T: RenderTarget> Canvas<T> {
 
     /// Gets device independent resolution for rendering.
     #[doc(alias = "SDL_GetRenderLogicalPresentation")]
-    pub fn logical_size(&self) -> (u32, u32, sys::render::SDL_RendererLogicalPresentation) {
+    pub fn logical_size(&self) -> (u32, u32, LogicalPresentation) {
+        let (width, height, mode) = self.raw_logical_size();
+        (width, height, mode.into())
+    }
+
+    /// Gets device independent resolution for rendering, with the raw presentation mode.
+    #[deprecated(note = "use `logical_size`, which returns a `LogicalPresentation`")]
+    #[doc(alias = "SDL_GetRenderLogicalPresentation")]
+    pub fn logical_size_raw(&self) -> (u32, u32, sys::render::SDL_RendererLogicalPresentation) {
+        self.raw_logical_size()
+    }
+
+    fn raw_logical_size(&self) -> (u32, u32, sys::render::SDL_RendererLogicalPresentation) {
         let mut width = 0;
         let mut height = 0;
         let mut mode: sys::render::SDL_RendererLogicalPresentation =
@@ -1228,7 +3461,7 @@ impl<T: RenderTarget> Canvas<T> {
 
         unsafe {
             sys::render::SDL_GetRenderLogicalPresentation(
-                self.context.raw,
+                self.context.checked_raw(),
                 &mut width,
                 &mut height,
                 &mut mode,
@@ -1238,52 +3471,219 @@ impl<T: RenderTarget> Canvas<T> {
         (width as u32, height as u32, mode)
     }
 
+    /// Gets the area of the output that the logical size is mapped to, in pixels.
+    ///
+    /// [`Canvas::output_size`] is the size of the whole output in pixels, and
+    /// [`Canvas::logical_size`] is the size of the area drawing happens in. This rect is where
+    /// that logical area ends up on the output: with letterboxing, it leaves out the bars on
+    /// the sides, and with overscan it may extend past the output. When logical presentation is
+    /// disabled, it covers the whole output.
+    #[doc(alias = "SDL_GetRenderLogicalPresentationRect")]
+    pub fn logical_presentation_rect(&self) -> Result<FRect, Error> {
+        let mut rect = mem::MaybeUninit::uninit();
+        let ret = unsafe {
+            sys::render::SDL_GetRenderLogicalPresentationRect(
+                self.context.checked_raw(),
+                rect.as_mut_ptr(),
+            )
+        };
+        if !ret {
+            return Err(get_error());
+        }
+        let rect = unsafe { rect.assume_init() };
+        Ok(FRect::new(rect.x, rect.y, rect.w, rect.h))
+    }
+
+    /// Captures the current window to render coordinate transform, to convert many points
+    /// without querying SDL for each one.
+    ///
+    /// The mapper is a snapshot: it is stale as soon as the window size, logical presentation,
+    /// scale or viewport change, so get a new one every frame, after those are set. Like
+    /// [`Canvas::render_coordinates_from_window`], it uses the scale and viewport of the
+    /// window or surface, so get it while that is the render target.
+    pub fn coordinate_mapper(&self) -> RenderCoordinateMapper {
+        let mut pixel_density = (1.0, 1.0);
+        let window = unsafe { sys::render::SDL_GetRenderWindow(self.context.checked_raw()) };
+        if !window.is_null() {
+            let (mut w, mut h, mut pw, mut ph) = (0, 0, 0, 0);
+            let ok = unsafe {
+                sys::video::SDL_GetWindowSize(window, &mut w, &mut h)
+                    && sys::video::SDL_GetWindowSizeInPixels(window, &mut pw, &mut ph)
+            };
+            if ok && w > 0 && h > 0 {
+                pixel_density = (pw as f32 / w as f32, ph as f32 / h as f32);
+            }
+        }
+
+        let (logical_w, logical_h, mode) = self.logical_size();
+        let logical = match (mode, self.logical_presentation_rect()) {
+            (LogicalPresentation::Disabled, _) | (_, Err(_)) => None,
+            (_, Ok(dst)) => Some(((logical_w as f32, logical_h as f32), dst)),
+        };
+        let viewport = self.viewport();
+
+        RenderCoordinateMapper {
+            pixel_density,
+            logical,
+            scale: self.scale(),
+            viewport_origin: (viewport.x() as f32, viewport.y() as f32),
+        }
+    }
+
+    /// Converts a point from window coordinates to render coordinates.
+    ///
+    /// This takes into account several states: the window dimensions, the logical presentation
+    /// set with [`Canvas::set_logical_size`], the scale set with [`Canvas::set_scale`] and the
+    /// viewport set with [`Canvas::set_viewport`].
+    ///
+    /// Mouse events are reported in window coordinates, so if a logical size or scale is set,
+    /// every mouse position must go through this function before hit-testing against what was
+    /// drawn, otherwise it will be wrong (especially on high-DPI displays). See also
+    /// [`Event::convert_coords`](crate::event::Event::convert_coords).
+    #[doc(alias = "SDL_RenderCoordinatesFromWindow")]
+    pub fn render_coordinates_from_window<P: Into<FPoint>>(
+        &self,
+        window_point: P,
+    ) -> Result<FPoint, Error> {
+        let window_point = window_point.into();
+        let (mut x, mut y) = (0.0, 0.0);
+        let ret = unsafe {
+            sys::render::SDL_RenderCoordinatesFromWindow(
+                self.context.checked_raw(),
+                window_point.x,
+                window_point.y,
+                &mut x,
+                &mut y,
+            )
+        };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(FPoint::new(x, y))
+        }
+    }
+
+    /// Converts a point from render coordinates to window coordinates.
+    ///
+    /// This is the inverse of [`Canvas::render_coordinates_from_window`].
+    #[doc(alias = "SDL_RenderCoordinatesToWindow")]
+    pub fn render_coordinates_to_window<P: Into<FPoint>>(&self, point: P) -> Result<FPoint, Error> {
+        let point = point.into();
+        let (mut window_x, mut window_y) = (0.0, 0.0);
+        let ret = unsafe {
+            sys::render::SDL_RenderCoordinatesToWindow(
+                self.context.checked_raw(),
+                point.x,
+                point.y,
+                &mut window_x,
+                &mut window_y,
+            )
+        };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(FPoint::new(window_x, window_y))
+        }
+    }
+
+    /// Converts the coordinates of an event from window coordinates to render coordinates, in
+    /// place.
+    ///
+    /// Mouse motion, mouse button, mouse wheel and touch finger events are converted with the
+    /// same rules as [`Canvas::render_coordinates_from_window`]; relative motion is scaled but
+    /// not offset. Any other event is left untouched.
+    #[doc(alias = "SDL_ConvertEventToRenderCoordinates")]
+    pub fn convert_event_to_render_coordinates(&self, event: &mut Event) -> Result<(), Error> {
+        match event {
+            Event::MouseMotion { .. }
+            | Event::MouseButtonDown { .. }
+            | Event::MouseButtonUp { .. }
+            | Event::MouseWheel { .. }
+            | Event::FingerDown { .. }
+            | Event::FingerUp { .. }
+            | Event::FingerMotion { .. } => {}
+            _ => return Ok(()),
+        }
+        let mut raw = match event.to_ll() {
+            Some(raw) => raw,
+            None => return Ok(()),
+        };
+        let ret = unsafe {
+            sys::render::SDL_ConvertEventToRenderCoordinates(self.context.checked_raw(), &mut raw)
+        };
+        if !ret {
+            return Err(get_error());
+        }
+        *event = Event::from_ll(raw);
+        Ok(())
+    }
+
     /// Sets the drawing area for rendering on the current target.
+    ///
+    /// Panics if SDL fails to set the viewport; see [`Canvas::try_set_viewport`].
     #[doc(alias = "SDL_SetRenderViewport")]
     pub fn set_viewport<R: Into<Option<Rect>>>(&mut self, rect: R) {
+        self.try_set_viewport(rect)
+            .unwrap_or_else(|e| panic!("Could not set viewport: {}", e))
+    }
+
+    /// Sets the drawing area for rendering on the current target.
+    ///
+    /// Errors if SDL fails to set the viewport.
+    #[doc(alias = "SDL_SetRenderViewport")]
+    pub fn try_set_viewport<R: Into<Option<Rect>>>(&mut self, rect: R) -> Result<(), Error> {
         let rect = rect.into();
         // as_ref is important because we need rect to live until the end of the FFI call, but map_or consumes an Option<T>
         let ptr = rect.as_ref().map_or(ptr::null(), |rect| rect.raw());
-        let ret = unsafe { sys::render::SDL_SetRenderViewport(self.context.raw, ptr) };
+        let ret = unsafe { sys::render::SDL_SetRenderViewport(self.context.checked_raw(), ptr) };
         if !ret {
-            panic!("Could not set viewport: {}", get_error())
+            Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
     /// Gets the drawing area for the current target.
+    ///
+    /// SDL already reports the viewport in post-scale render coordinates, the same space as
+    /// the draw calls: with a [`Canvas::set_scale`] of 2.0, the viewport of an 800x600 target
+    /// is 400x300, so it can be culled against directly without dividing by the scale.
     #[doc(alias = "SDL_GetRenderViewport")]
     pub fn viewport(&self) -> Rect {
         let mut rect = mem::MaybeUninit::uninit();
-        unsafe { sys::render::SDL_GetRenderViewport(self.context.raw, rect.as_mut_ptr()) };
+        unsafe {
+            sys::render::SDL_GetRenderViewport(self.context.checked_raw(), rect.as_mut_ptr())
+        };
         let rect = unsafe { rect.assume_init() };
         Rect::from_ll(rect)
     }
 
+    /// Gets the safe area for rendering within the current viewport.
+    ///
+    /// Some devices have portions of the screen which are partially obscured or not
+    /// interactive, such as notches or rounded corners. The returned rect is the area of the
+    /// viewport, in render coordinates, that is safe for interactive or important content.
+    /// When the output has no such cutouts, this is the full viewport.
+    #[doc(alias = "SDL_GetRenderSafeArea")]
+    pub fn safe_area(&self) -> Result<Rect, Error> {
+        let mut rect = mem::MaybeUninit::uninit();
+        let ret = unsafe {
+            sys::render::SDL_GetRenderSafeArea(self.context.checked_raw(), rect.as_mut_ptr())
+        };
+        if !ret {
+            return Err(get_error());
+        }
+        let rect = unsafe { rect.assume_init() };
+        Ok(Rect::from_ll(rect))
+    }
+
     /// Sets the clip rectangle for rendering on the specified target.
     #[doc(alias = "SDL_SetRenderClipRect")]
     pub fn set_clip_rect<R>(&mut self, arg: R)
     where
         R: Into<ClippingRect>,
     {
-        let arg: ClippingRect = arg.into();
-        let ret = match arg {
-            ClippingRect::Some(r) => unsafe {
-                sdl3_sys::everything::SDL_SetRenderClipRect(self.context.raw, r.raw())
-            },
-            ClippingRect::Zero => {
-                let r = sdl3_sys::everything::SDL_Rect {
-                    x: 0,
-                    y: 0,
-                    w: 0,
-                    h: 0,
-                };
-                let r: *const sdl3_sys::everything::SDL_Rect = &r;
-                unsafe { sdl3_sys::everything::SDL_SetRenderClipRect(self.context.raw, r) }
-            }
-            ClippingRect::None => unsafe {
-                sdl3_sys::everything::SDL_SetRenderClipRect(self.context.raw, ptr::null())
-            },
-        };
+        let ret = unsafe { set_raw_clip_rect(self.context.checked_raw(), arg.into()) };
         if !ret {
             panic!("Could not set clip rect: {}", get_error())
         }
@@ -1292,14 +3692,20 @@ impl<T: RenderTarget> Canvas<T> {
     /// Gets the clip rectangle for the current target.
     #[doc(alias = "SDL_GetRenderClipRect")]
     pub fn clip_rect(&self) -> ClippingRect {
-        let clip_enabled = unsafe { sdl3_sys::everything::SDL_RenderClipEnabled(self.context.raw) };
+        let clip_enabled =
+            unsafe { sdl3_sys::everything::SDL_RenderClipEnabled(self.context.checked_raw()) };
 
         if !clip_enabled {
             return ClippingRect::None;
         }
 
         let mut raw = mem::MaybeUninit::uninit();
-        unsafe { sdl3_sys::everything::SDL_GetRenderClipRect(self.context.raw, raw.as_mut_ptr()) };
+        unsafe {
+            sdl3_sys::everything::SDL_GetRenderClipRect(
+                self.context.checked_raw(),
+                raw.as_mut_ptr(),
+            )
+        };
         let raw = unsafe { raw.assume_init() };
         if raw.w == 0 || raw.h == 0 {
             ClippingRect::Zero
@@ -1308,15 +3714,90 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
-    /// Sets the drawing scale for rendering on the current target.
+    /// Sets the drawing area to `rect` while `f` runs, then restores the previous one.
+    ///
+    /// The previous viewport is restored even if `f` panics. Calls can be nested.
+    ///
+    /// Panics if SDL fails to set the viewport.
+    #[doc(alias = "SDL_SetRenderViewport")]
+    pub fn with_viewport<R, F, Ret>(&mut self, rect: R, f: F) -> Ret
+    where
+        R: Into<Option<Rect>>,
+        F: FnOnce(&mut Canvas<T>) -> Ret,
+    {
+        let previous = if unsafe { sys::render::SDL_RenderViewportSet(self.context.checked_raw()) }
+        {
+            Some(self.viewport())
+        } else {
+            None
+        };
+        let _restore = RestoreRenderState::Viewport(self.context.checked_raw(), previous);
+        self.set_viewport(rect);
+        f(self)
+    }
+
+    /// Sets the clip rect to `rect` while `f` runs, then restores the previous one.
+    ///
+    /// The previous clip rect is restored even if `f` panics. Calls can be nested. Unlike
+    /// [`Canvas::push_clip`], the clip rect is replaced rather than intersected.
+    ///
+    /// Panics if SDL fails to set the clip rect.
+    #[doc(alias = "SDL_SetRenderClipRect")]
+    pub fn with_clip_rect<R, F, Ret>(&mut self, rect: R, f: F) -> Ret
+    where
+        R: Into<ClippingRect>,
+        F: FnOnce(&mut Canvas<T>) -> Ret,
+    {
+        let _restore = RestoreRenderState::ClipRect(self.context.checked_raw(), self.clip_rect());
+        self.set_clip_rect(rect);
+        f(self)
+    }
+
+    /// Pushes a new clip rect, clipping to the intersection of the current clip rect and `rect`.
+    ///
+    /// The current clip rect is saved and restored by the matching [`Canvas::pop_clip`]. This is
+    /// meant for nested UI, where a child should never draw outside of its parent.
+    pub fn push_clip(&mut self, rect: Rect) {
+        let current = self.clip_rect();
+        self.clip_stack.push(current);
+        self.set_clip_rect(current.intersection(ClippingRect::Some(rect)));
+    }
+
+    /// Restores the clip rect that was active before the last [`Canvas::push_clip`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, and leaves the clip rect untouched, if there is no matching
+    /// `push_clip` call, i.e. the clip stack is empty.
+    pub fn pop_clip(&mut self) -> Result<(), Error> {
+        match self.clip_stack.pop() {
+            Some(parent) => {
+                self.set_clip_rect(parent);
+                Ok(())
+            }
+            None => Err(Error(
+                "pop_clip called without a matching push_clip".to_owned(),
+            )),
+        }
+    }
+
+    /// Sets the drawing scale for rendering on the current target, and returns the previous
+    /// scale so it can be restored afterwards.
+    ///
+    /// Scales that are not finite and positive are rejected with an error without calling
+    /// into SDL.
     #[doc(alias = "SDL_SetRenderScale")]
-    pub fn set_scale(&mut self, scale_x: f32, scale_y: f32) -> Result<(), Error> {
-        let ret = unsafe { sys::render::SDL_SetRenderScale(self.context.raw, scale_x, scale_y) };
+    pub fn set_scale(&mut self, scale_x: f32, scale_y: f32) -> Result<(f32, f32), Error> {
+        validate_scale(scale_x, scale_y)?;
+        let previous = self.scale();
+        let ret = unsafe {
+            sys::render::SDL_SetRenderScale(self.context.checked_raw(), scale_x, scale_y)
+        };
         // Should only fail on an invalid renderer
         if !ret {
             Err(get_error())
         } else {
-            Ok(())
+            Ok(previous)
         }
     }
 
@@ -1325,16 +3806,154 @@ impl<T: RenderTarget> Canvas<T> {
     pub fn scale(&self) -> (f32, f32) {
         let mut scale_x = 0.0;
         let mut scale_y = 0.0;
-        unsafe { sys::render::SDL_GetRenderScale(self.context.raw, &mut scale_x, &mut scale_y) };
+        unsafe {
+            sys::render::SDL_GetRenderScale(self.context.checked_raw(), &mut scale_x, &mut scale_y)
+        };
         (scale_x, scale_y)
     }
 
+    /// Sets the color scale used for render operations.
+    ///
+    /// The color scale is an additional scale multiplied into the pixel color value while
+    /// rendering. This can be used to adjust the brightness of colors during HDR rendering, or
+    /// changing HDR video brightness when playing on an SDR display. For example, SDR content
+    /// such as UI can be drawn at the correct brightness over HDR content by setting the scale
+    /// to the output's SDR white point.
+    ///
+    /// The color scale does not affect the alpha channel, only the color brightness. It is
+    /// applied on top of the draw color, texture color/alpha modulation and vertex colors, so
+    /// it composes with float draw colors that already exceed 1.0.
+    ///
+    /// The default color scale is 1.0. Negative and NaN scales are rejected with an error
+    /// without calling into SDL.
+    #[doc(alias = "SDL_SetRenderColorScale")]
+    pub fn set_color_scale(&mut self, scale: f32) -> Result<(), Error> {
+        if scale.is_nan() || scale < 0.0 {
+            return Err(Error(format!("invalid color scale: {}", scale)));
+        }
+        let ret =
+            unsafe { sys::render::SDL_SetRenderColorScale(self.context.checked_raw(), scale) };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Gets the color scale used for render operations.
+    #[doc(alias = "SDL_GetRenderColorScale")]
+    pub fn color_scale(&self) -> f32 {
+        let mut scale = 1.0;
+        let ret =
+            unsafe { sys::render::SDL_GetRenderColorScale(self.context.checked_raw(), &mut scale) };
+        // Should only fail on an invalid renderer
+        if !ret {
+            panic!("{}", get_error())
+        }
+        scale
+    }
+
     /// Draws a point on the current rendering target.
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderPoint")]
     pub fn draw_point<P: Into<FPoint>>(&mut self, point: P) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
         let point = point.into();
-        let result = unsafe { sys::render::SDL_RenderPoint(self.context.raw, point.x, point.y) };
+        let result =
+            unsafe { sys::render::SDL_RenderPoint(self.context.checked_raw(), point.x, point.y) };
+        if !result {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Draws debug text on the current rendering target using SDL's built-in 8x8 bitmap font.
+    ///
+    /// Each glyph is [`DEBUG_TEXT_FONT_CHARACTER_SIZE`] pixels square, so lines can be laid out
+    /// by offsetting `position.y` by that amount. The text is drawn with the current draw color.
+    ///
+    /// Errors if `text` contains an interior NUL byte, or if drawing fails.
+    #[doc(alias = "SDL_RenderDebugText")]
+    pub fn render_debug_text<P: Into<FPoint>>(
+        &mut self,
+        position: P,
+        text: &str,
+    ) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
+        let position = position.into();
+        let text = CString::new(text).map_err(|e| Error(e.to_string()))?;
+        let result = unsafe {
+            sys::render::SDL_RenderDebugText(
+                self.context.checked_raw(),
+                position.x,
+                position.y,
+                text.as_ptr(),
+            )
+        };
+        if !result {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Formats `args` and draws the result as debug text. See [`Canvas::render_debug_text`].
+    ///
+    /// The text is formatted into a buffer kept by the `Canvas`, so repeated calls don't
+    /// allocate once it has grown to fit. The [`render_debug_text!`](crate::render_debug_text)
+    /// macro calls this method.
+    pub fn render_debug_text_fmt(
+        &mut self,
+        position: FPoint,
+        args: fmt::Arguments,
+    ) -> Result<(), Error> {
+        let mut buffer = mem::take(&mut self.debug_text_buffer);
+        let result = self.render_debug_text_fmt_with(&mut buffer, position, args);
+        self.debug_text_buffer = buffer;
+        result
+    }
+
+    /// Formats `args` into `buffer` and draws the result as debug text.
+    ///
+    /// `buffer` is cleared before formatting, and is only used as scratch space so its
+    /// allocation can be reused across calls.
+    ///
+    /// Errors if the formatted text contains an interior NUL byte, or if drawing fails.
+    #[doc(alias = "SDL_RenderDebugText")]
+    pub fn render_debug_text_fmt_with(
+        &mut self,
+        buffer: &mut String,
+        position: FPoint,
+        args: fmt::Arguments,
+    ) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
+        use std::fmt::Write;
+
+        buffer.clear();
+        buffer.write_fmt(args).map_err(|e| Error(e.to_string()))?;
+        if let Some(index) = buffer.find('\0') {
+            return Err(Error(format!(
+                "nul byte found in provided data at position: {}",
+                index
+            )));
+        }
+        buffer.push('\0');
+
+        let result = unsafe {
+            sys::render::SDL_RenderDebugText(
+                self.context.checked_raw(),
+                position.x,
+                position.y,
+                buffer.as_ptr() as *const libc::c_char,
+            )
+        };
         if !result {
             Err(get_error())
         } else {
@@ -1346,10 +3965,13 @@ impl<T: RenderTarget> Canvas<T> {
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderPoints")]
     pub fn draw_points<'a, P: Into<&'a [FPoint]>>(&mut self, points: P) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
         let points = points.into();
         let result = unsafe {
             sys::render::SDL_RenderPoints(
-                self.context.raw,
+                self.context.checked_raw(),
                 points.as_ptr() as *const sys::rect::SDL_FPoint,
                 points.len() as c_int,
             )
@@ -1369,10 +3991,13 @@ impl<T: RenderTarget> Canvas<T> {
         start: P1,
         end: P2,
     ) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
         let start = start.into();
         let end = end.into();
         let result = unsafe {
-            sys::render::SDL_RenderLine(self.context.raw, start.x, start.y, end.x, end.y)
+            sys::render::SDL_RenderLine(self.context.checked_raw(), start.x, start.y, end.x, end.y)
         };
         if !result {
             Err(get_error())
@@ -1385,10 +4010,13 @@ impl<T: RenderTarget> Canvas<T> {
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderLines")]
     pub fn draw_lines<'a, P: Into<&'a [FPoint]>>(&mut self, points: P) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
         let points = points.into();
         let result = unsafe {
             sys::render::SDL_RenderLines(
-                self.context.raw,
+                self.context.checked_raw(),
                 points
                     .iter()
                     .map(|p| p.to_ll())
@@ -1408,9 +4036,12 @@ impl<T: RenderTarget> Canvas<T> {
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderRect")]
     pub fn draw_rect(&mut self, rect: FRect) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
         let rect = rect.to_ll();
 
-        let result = unsafe { sys::render::SDL_RenderRect(self.context.raw, &rect) };
+        let result = unsafe { sys::render::SDL_RenderRect(self.context.checked_raw(), &rect) };
         if !result {
             Err(get_error())
         } else {
@@ -1422,10 +4053,13 @@ impl<T: RenderTarget> Canvas<T> {
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderRects")]
     pub fn draw_rects(&mut self, rects: &[FRect]) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
         let result = unsafe {
             sys::render::SDL_RenderRects(
-                self.context.raw,
-                rects.iter().map(|r| r.to_ll()).collect::<Vec<_>>().as_ptr(),
+                self.context.checked_raw(),
+                rects.as_ptr() as *const sys::rect::SDL_FRect,
                 rects.len() as c_int,
             )
         };
@@ -1442,10 +4076,13 @@ impl<T: RenderTarget> Canvas<T> {
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderFillRect")]
     pub fn fill_rect<R: Into<Option<FRect>>>(&mut self, rect: R) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
         let rect_ll = rect.into().map(|r| r.to_ll());
         let result = unsafe {
             sys::render::SDL_RenderFillRect(
-                self.context.raw,
+                self.context.checked_raw(),
                 rect_ll.as_ref().map_or(ptr::null(), |r| r),
             )
         };
@@ -1456,15 +4093,43 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Draws a point at integer coordinates. Same as [`Canvas::draw_point`].
+    #[inline]
+    pub fn draw_point_i(&mut self, point: Point) -> Result<(), Error> {
+        self.draw_point(FPoint::from(point))
+    }
+
+    /// Draws a line between integer coordinates. Same as [`Canvas::draw_line`].
+    #[inline]
+    pub fn draw_line_i(&mut self, start: Point, end: Point) -> Result<(), Error> {
+        self.draw_line(FPoint::from(start), FPoint::from(end))
+    }
+
+    /// Draws the outline of an integer rectangle. Same as [`Canvas::draw_rect`].
+    #[inline]
+    pub fn draw_rect_i(&mut self, rect: Rect) -> Result<(), Error> {
+        self.draw_rect(FRect::from(rect))
+    }
+
+    /// Fills an integer rectangle, or the entire rendering target if `rect` is `None`. Same as
+    /// [`Canvas::fill_rect`].
+    #[inline]
+    pub fn fill_rect_i<R: Into<Option<Rect>>>(&mut self, rect: R) -> Result<(), Error> {
+        self.fill_rect(rect.into().map(FRect::from))
+    }
+
     /// Fills some number of rectangles on the current rendering target with
     /// the drawing color.
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderFillRects")]
     pub fn fill_rects(&mut self, rects: &[FRect]) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
         let result = unsafe {
             sys::render::SDL_RenderFillRects(
-                self.context.raw,
-                rects.iter().map(|r| r.to_ll()).collect::<Vec<_>>().as_ptr(),
+                self.context.checked_raw(),
+                rects.as_ptr() as *const sys::rect::SDL_FRect,
                 rects.len() as c_int,
             )
         };
@@ -1475,9 +4140,245 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
-    /// Copies a portion of the texture to the current rendering target.
+    /// Fills a rectangle with a checkerboard of `cell`-sized squares, alternating between
+    /// `color_a` and `color_b` starting with `color_a` in the top-left corner.
     ///
-    /// * If `src` is `None`, the entire texture is copied.
+    /// Cells on the right and bottom edges are cut off to fit `rect`. All cells are submitted
+    /// as a single geometry call, regardless of the size of the area.
+    ///
+    /// Errors if `cell` is not finite or smaller than one pixel, if `rect` would need more
+    /// cells than a single geometry call can index, or if drawing fails for any reason.
+    pub fn fill_checkerboard(
+        &mut self,
+        rect: FRect,
+        cell: f32,
+        color_a: pixels::Color,
+        color_b: pixels::Color,
+    ) -> Result<(), Error> {
+        if !(cell.is_finite() && cell >= 1.0) {
+            return Err(Error(format!("invalid checkerboard cell size: {}", cell)));
+        }
+        if !self.draw_enabled || rect.w <= 0.0 || rect.h <= 0.0 {
+            return Ok(());
+        }
+        let columns = (rect.w / cell).ceil() as usize;
+        let rows = (rect.h / cell).ceil() as usize;
+        // Each cell takes 6 indices, which must fit in an `i32`.
+        let cells = columns
+            .checked_mul(rows)
+            .filter(|&cells| cells <= i32::MAX as usize / 6)
+            .ok_or_else(|| {
+                Error(format!(
+                    "too many checkerboard cells: {} x {}",
+                    columns, rows
+                ))
+            })?;
+        let color_a = pixels::FColor::from(color_a);
+        let color_b = pixels::FColor::from(color_b);
+        let no_uv = FPoint::new(0.0, 0.0);
+
+        let mut vertices = Vec::with_capacity(cells * 4);
+        let mut indices = Vec::with_capacity(cells * 6);
+        for row in 0..rows {
+            let y0 = rect.y + row as f32 * cell;
+            let y1 = (y0 + cell).min(rect.y + rect.h);
+            for column in 0..columns {
+                let x0 = rect.x + column as f32 * cell;
+                let x1 = (x0 + cell).min(rect.x + rect.w);
+                let color = if (row + column) % 2 == 0 {
+                    color_a
+                } else {
+                    color_b
+                };
+                let base = vertices.len() as i32;
+                vertices.extend_from_slice(&[
+                    Vertex::new(FPoint::new(x0, y0), color, no_uv),
+                    Vertex::new(FPoint::new(x1, y0), color, no_uv),
+                    Vertex::new(FPoint::new(x1, y1), color, no_uv),
+                    Vertex::new(FPoint::new(x0, y1), color, no_uv),
+                ]);
+                indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+            }
+        }
+        self.render_geometry(&vertices, None, Some(&indices))
+    }
+
+    /// Fills a circle with the current draw color.
+    ///
+    /// The circle is approximated by a polygon with [`circle_segments`]`(radius)` sides; use
+    /// [`Canvas::fill_circle_segments`] to choose the number of sides.
+    ///
+    /// Errors if `radius` is negative or NaN, or if drawing fails for any reason.
+    pub fn fill_circle(&mut self, center: FPoint, radius: f32) -> Result<(), Error> {
+        self.fill_circle_segments(center, radius, circle_segments(radius))
+    }
+
+    /// Fills a circle with the current draw color, approximated by a polygon with `segments`
+    /// sides and drawn as a triangle fan.
+    ///
+    /// Errors if `radius` is negative or NaN, if `segments` is less than 3, or if drawing fails
+    /// for any reason.
+    pub fn fill_circle_segments(
+        &mut self,
+        center: FPoint,
+        radius: f32,
+        segments: usize,
+    ) -> Result<(), Error> {
+        validate_circle(radius, segments)?;
+        if !self.draw_enabled || radius == 0.0 {
+            return Ok(());
+        }
+        let color = self.try_draw_color_float()?;
+        let no_uv = FPoint::new(0.0, 0.0);
+
+        let mut vertices = Vec::with_capacity(segments + 1);
+        vertices.push(Vertex::new(center, color, no_uv));
+        vertices.extend(
+            circle_points(center, radius, segments)
+                .take(segments)
+                .map(|point| Vertex::new(point, color, no_uv)),
+        );
+        let mut indices = Vec::with_capacity(segments * 3);
+        for i in 1..=segments as i32 {
+            indices.extend_from_slice(&[0, i, i % segments as i32 + 1]);
+        }
+        self.render_geometry(&vertices, None, Some(&indices))
+    }
+
+    /// Draws a dashed line from `start` to `end` with the current draw color, alternating
+    /// `dash_len` drawn and `gap_len` skipped, starting with a dash.
+    ///
+    /// A zero-length line draws nothing, and a `gap_len` of 0 draws a solid line. The dashes
+    /// are one pixel wide and are submitted together as a single batch of geometry.
+    ///
+    /// Errors if `dash_len` is not finite and positive, if `gap_len` is not finite and
+    /// non-negative, if the line would need more than 65536 dashes, or if drawing fails for
+    /// any reason.
+    #[doc(alias = "SDL_RenderGeometry")]
+    pub fn draw_dashed_line(
+        &mut self,
+        start: FPoint,
+        end: FPoint,
+        dash_len: f32,
+        gap_len: f32,
+    ) -> Result<(), Error> {
+        if !(dash_len.is_finite() && dash_len > 0.0) {
+            return Err(Error(format!("invalid dash length: {}", dash_len)));
+        }
+        if !(gap_len.is_finite() && gap_len >= 0.0) {
+            return Err(Error(format!("invalid gap length: {}", gap_len)));
+        }
+        let segments = dash_segments(start, end, dash_len, gap_len)?;
+        if !self.draw_enabled || segments.is_empty() {
+            return Ok(());
+        }
+        let color = self.try_draw_color_float()?;
+        let no_uv = FPoint::new(0.0, 0.0);
+        let mut vertices = Vec::with_capacity(segments.len() * 4);
+        let mut indices = Vec::with_capacity(segments.len() * 6);
+        for (from, to) in segments {
+            let (points, quad) = thick_line_geometry(from, to, 1.0, LineCap::Butt);
+            let first = vertices.len() as i32;
+            indices.extend(quad.into_iter().map(|index| first + index));
+            vertices.extend(
+                points
+                    .into_iter()
+                    .map(|point| Vertex::new(point, color, no_uv)),
+            );
+        }
+        self.render_geometry(&vertices, None, Some(&indices))
+    }
+
+    /// Draws a line of the given width with `color`, independently of the current draw color.
+    ///
+    /// Unlike [`Canvas::draw_line`], which is always one pixel wide, the line is drawn as two
+    /// triangles, so it keeps its width at any angle and scale. The ends are flat; see
+    /// [`Canvas::draw_line_thick_with_cap`] for rounded ends. A zero-length line draws nothing.
+    ///
+    /// Errors if `width` is not finite and positive, or if drawing fails for any reason.
+    #[doc(alias = "SDL_RenderGeometry")]
+    pub fn draw_line_thick(
+        &mut self,
+        start: FPoint,
+        end: FPoint,
+        width: f32,
+        color: pixels::Color,
+    ) -> Result<(), Error> {
+        self.draw_line_thick_with_cap(start, end, width, color, LineCap::Butt)
+    }
+
+    /// Draws a line of the given width with `color`, like [`Canvas::draw_line_thick`], with
+    /// the given style of ends.
+    ///
+    /// With `LineCap::Round`, a zero-length line draws a dot of diameter `width`.
+    ///
+    /// Errors if `width` is not finite and positive, or if drawing fails for any reason.
+    #[doc(alias = "SDL_RenderGeometry")]
+    pub fn draw_line_thick_with_cap(
+        &mut self,
+        start: FPoint,
+        end: FPoint,
+        width: f32,
+        color: pixels::Color,
+        cap: LineCap,
+    ) -> Result<(), Error> {
+        if !(width.is_finite() && width > 0.0) {
+            return Err(Error(format!("invalid line width: {}", width)));
+        }
+        if !self.draw_enabled {
+            return Ok(());
+        }
+        let (points, indices) = thick_line_geometry(start, end, width, cap);
+        if points.is_empty() {
+            return Ok(());
+        }
+        let no_uv = FPoint::new(0.0, 0.0);
+        let vertices: Vec<Vertex> = points
+            .into_iter()
+            .map(|point| Vertex::new(point, color, no_uv))
+            .collect();
+        self.render_geometry(&vertices, None, Some(&indices))
+    }
+
+    /// Fills a convex polygon with `color`, independently of the current draw color.
+    ///
+    /// The polygon is split into a triangle fan around `points[0]`, so the result is undefined
+    /// for concave or self-intersecting polygons. The points may be in either winding order.
+    /// Fewer than 3 points draw nothing.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure).
+    #[doc(alias = "SDL_RenderGeometry")]
+    pub fn fill_polygon(&mut self, points: &[FPoint], color: pixels::Color) -> Result<(), Error> {
+        if !self.draw_enabled || points.len() < 3 {
+            return Ok(());
+        }
+        let no_uv = FPoint::new(0.0, 0.0);
+        let vertices: Vec<Vertex> = points
+            .iter()
+            .map(|&point| Vertex::new(point, color, no_uv))
+            .collect();
+        let indices = polygon_fan_indices(points.len());
+        self.render_geometry(&vertices, None, Some(&indices))
+    }
+
+    /// Draws the outline of a circle with the current draw color.
+    ///
+    /// The circle is approximated by a polygon with [`circle_segments`]`(radius)` sides.
+    ///
+    /// Errors if `radius` is negative or NaN, or if drawing fails for any reason.
+    pub fn draw_circle(&mut self, center: FPoint, radius: f32) -> Result<(), Error> {
+        let segments = circle_segments(radius);
+        validate_circle(radius, segments)?;
+        if !self.draw_enabled {
+            return Ok(());
+        }
+        let points: Vec<FPoint> = circle_points(center, radius, segments).collect();
+        self.draw_lines(&points[..])
+    }
+
+    /// Copies a portion of the texture to the current rendering target.
+    ///
+    /// * If `src` is `None`, the entire texture is copied.
     /// * If `dst` is `None`, the texture will be stretched to fill the given
     ///   rectangle.
     ///
@@ -1489,12 +4390,15 @@ impl<T: RenderTarget> Canvas<T> {
         R1: Into<Option<FRect>>,
         R2: Into<Option<FRect>>,
     {
+        if !self.draw_enabled || self.is_transparent_copy(texture) {
+            return Ok(());
+        }
         let src = src.into().map(|rect| rect.to_ll());
         let dst = dst.into().map(|rect| rect.to_ll());
 
         let ret = unsafe {
             sys::render::SDL_RenderTexture(
-                self.context.raw,
+                self.context.checked_raw(),
                 texture.raw,
                 match src {
                     Some(ref rect) => rect,
@@ -1514,6 +4418,186 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Copies a portion of the texture to the current rendering target, with the source
+    /// rectangle given in normalized coordinates.
+    ///
+    /// `src_norm` is scaled by the texture size, so `FRect::new(0.0, 0.0, 1.0, 1.0)` is the
+    /// whole texture and `FRect::new(0.5, 0.0, 0.5, 1.0)` its right half. `dst` behaves as in
+    /// [`Canvas::copy`].
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderTexture")]
+    pub fn copy_norm<R>(&mut self, texture: &Texture, src_norm: FRect, dst: R) -> Result<(), Error>
+    where
+        R: Into<Option<FRect>>,
+    {
+        let (width, height) = (texture.width() as f32, texture.height() as f32);
+        let src = FRect::new(
+            src_norm.x * width,
+            src_norm.y * height,
+            src_norm.w * width,
+            src_norm.h * height,
+        );
+        self.copy(texture, src, dst)
+    }
+
+    /// Fills `dst` by repeating a portion of the texture, instead of stretching it.
+    ///
+    /// * If `src` is `None`, the entire texture is repeated.
+    /// * `scale` is applied to the size of `src` to get the size of each tile, e.g. `2.0`
+    ///   draws every tile twice as large.
+    ///
+    /// Tiles start at the top-left corner of `dst`, and are cut off at its right and bottom
+    /// edges.
+    ///
+    /// Errors if `scale` is not positive, if drawing fails for any reason (e.g. driver
+    /// failure), or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderTextureTiled")]
+    pub fn copy_tiled(
+        &mut self,
+        texture: &Texture,
+        src: Option<FRect>,
+        scale: f32,
+        dst: FRect,
+    ) -> Result<(), Error> {
+        if scale.is_nan() || scale <= 0.0 {
+            return Err(Error(format!("invalid tile scale: {}", scale)));
+        }
+        if !self.draw_enabled || self.is_transparent_copy(texture) {
+            return Ok(());
+        }
+        let src = src.map(|rect| rect.to_ll());
+        let dst = dst.to_ll();
+
+        let ret = unsafe {
+            sys::render::SDL_RenderTextureTiled(
+                self.context.checked_raw(),
+                texture.raw,
+                match src {
+                    Some(ref rect) => rect,
+                    None => ptr::null(),
+                },
+                scale,
+                &dst,
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Copies a region of the texture that may extend past its edges, repeating the texture to
+    /// fill it, e.g. for a scrolling background.
+    ///
+    /// `src` is in texture pixels and may be larger than the texture or start at any offset,
+    /// including negative ones; it is wrapped around the texture's edges. It is stretched to
+    /// `dst` like with [`Canvas::copy`]. The fewest `copy` calls needed are issued, one per
+    /// visible tile, with partial tiles at the edges.
+    ///
+    /// Errors if `src` is not finite or has no area, if it would repeat the texture more than
+    /// 65536 times, or if drawing fails for any reason (e.g. driver failure), or if the provided
+    /// texture does not belong to the renderer.
+    pub fn copy_wrapped(&mut self, texture: &Texture, src: FRect, dst: FRect) -> Result<(), Error> {
+        if !(src.x.is_finite() && src.y.is_finite() && src.w.is_finite() && src.h.is_finite())
+            || !(src.w > 0.0 && src.h > 0.0)
+        {
+            return Err(Error(format!(
+                "invalid wrapped copy source: {}x{} at ({}, {})",
+                src.w, src.h, src.x, src.y
+            )));
+        }
+        let (width, height) = texture.size();
+        // Every run but the first and the last covers the whole texture.
+        let tiles = ((src.w / width).ceil() + 1.0) * ((src.h / height).ceil() + 1.0);
+        if tiles > MAX_WRAPPED_TILES {
+            return Err(Error(format!(
+                "wrapped copy source {}x{} repeats a {}x{} texture too many times",
+                src.w, src.h, width, height
+            )));
+        }
+        if !self.draw_enabled || self.is_transparent_copy(texture) {
+            return Ok(());
+        }
+        let scale_x = dst.w / src.w;
+        let scale_y = dst.h / src.h;
+        let columns = wrap_segments(src.x, src.w, width);
+        let rows = wrap_segments(src.y, src.h, height);
+        for &(v, h, offset_y) in &rows {
+            for &(u, w, offset_x) in &columns {
+                self.copy(
+                    texture,
+                    FRect::new(u, v, w, h),
+                    FRect::new(
+                        dst.x + offset_x * scale_x,
+                        dst.y + offset_y * scale_y,
+                        w * scale_x,
+                        h * scale_y,
+                    ),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws a scalable "nine-patch" texture, e.g. for resizable UI panels.
+    ///
+    /// `src` (or the entire texture, if `None`) is cut into a 3x3 grid by `left_width`,
+    /// `right_width`, `top_height` and `bottom_height`. The corners are drawn at a fixed size,
+    /// the edges are stretched along one axis and the center along both, to fill `dst`.
+    /// `scale` is applied to the corner sizes, e.g. `2.0` for a high-DPI display.
+    ///
+    /// Errors if `scale` is not positive, if drawing fails for any reason (e.g. driver
+    /// failure), or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderTexture9Grid")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_9grid(
+        &mut self,
+        texture: &Texture,
+        src: Option<FRect>,
+        left_width: f32,
+        right_width: f32,
+        top_height: f32,
+        bottom_height: f32,
+        scale: f32,
+        dst: FRect,
+    ) -> Result<(), Error> {
+        if scale.is_nan() || scale <= 0.0 {
+            return Err(Error(format!("invalid 9-grid scale: {}", scale)));
+        }
+        if !self.draw_enabled || self.is_transparent_copy(texture) {
+            return Ok(());
+        }
+        let src = src.map(|rect| rect.to_ll());
+        let dst = dst.to_ll();
+
+        let ret = unsafe {
+            sys::render::SDL_RenderTexture9Grid(
+                self.context.checked_raw(),
+                texture.raw,
+                match src {
+                    Some(ref rect) => rect,
+                    None => ptr::null(),
+                },
+                left_width,
+                right_width,
+                top_height,
+                bottom_height,
+                scale,
+                &dst,
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
     /// Copies a portion of the texture to the current rendering target,
     /// optionally rotating it by angle around the given center and also
     /// flipping it top-bottom and/or left-right.
@@ -1543,17 +4627,10 @@ impl<T: RenderTarget> Canvas<T> {
         R2: Into<Option<FRect>>,
         P: Into<Option<FPoint>>,
     {
-        let flip = unsafe {
-            match (flip_horizontal, flip_vertical) {
-                (false, false) => SDL_FLIP_NONE,
-                (true, false) => SDL_FLIP_HORIZONTAL,
-                (false, true) => SDL_FLIP_VERTICAL,
-                (true, true) => transmute::<u32, sys::surface::SDL_FlipMode>(
-                    transmute::<sys::surface::SDL_FlipMode, u32>(SDL_FLIP_HORIZONTAL)
-                        | transmute::<sys::surface::SDL_FlipMode, u32>(SDL_FLIP_VERTICAL),
-                ),
-            }
-        };
+        if !self.draw_enabled || self.is_transparent_copy(texture) {
+            return Ok(());
+        }
+        let flip = flip_mode(flip_horizontal, flip_vertical);
 
         let src = src.into().map(|rect| rect.to_ll());
         let dst = dst.into().map(|rect| rect.to_ll());
@@ -1561,7 +4638,7 @@ impl<T: RenderTarget> Canvas<T> {
 
         let ret = unsafe {
             sys::render::SDL_RenderTextureRotated(
-                self.context.raw,
+                self.context.checked_raw(),
                 texture.raw,
                 match src {
                     Some(ref rect) => rect,
@@ -1587,15 +4664,317 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
-    /// Reads pixels from the current rendering target.
+    /// Copies a portion of the texture to the current rendering target with an affine
+    /// transform, which can express shear and non-uniform scaling as well as rotation.
+    ///
+    /// * If `src` is `None`, the entire texture is copied.
+    /// * `origin` is where the top-left corner of `src` ends up.
+    /// * `right` is where the top-right corner of `src` ends up.
+    /// * `down` is where the bottom-left corner of `src` ends up.
+    ///
+    /// The bottom-right corner is implied by the other three, so the result is always a
+    /// parallelogram; use [`Canvas::copy_quad`] for arbitrary quads.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderTextureAffine")]
+    pub fn copy_affine(
+        &mut self,
+        texture: &Texture,
+        src: Option<FRect>,
+        origin: FPoint,
+        right: FPoint,
+        down: FPoint,
+    ) -> Result<(), Error> {
+        if !self.draw_enabled || self.is_transparent_copy(texture) {
+            return Ok(());
+        }
+        let src = src.map(|rect| rect.to_ll());
+        let (origin, right, down) = (origin.to_ll(), right.to_ll(), down.to_ll());
+
+        let ret = unsafe {
+            sys::render::SDL_RenderTextureAffine(
+                self.context.checked_raw(),
+                texture.raw,
+                match src {
+                    Some(ref rect) => rect,
+                    None => ptr::null(),
+                },
+                &origin,
+                &right,
+                &down,
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Draws a textured quad with arbitrary corner positions and texture coordinates.
+    ///
+    /// `dst_corners` and `src_uvs` are given in the same order, going around the quad: top-left,
+    /// top-right, bottom-right, bottom-left. `src_uvs` are normalized texture coordinates, where
+    /// `(0.0, 0.0)` is the top-left of the texture and `(1.0, 1.0)` its bottom-right. The texture
+    /// color is multiplied by `tint`; use `Color::WHITE` to draw it unchanged.
+    ///
+    /// Unlike [`Canvas::copy_ex`], this can draw a region that is rotated or sheared inside the
+    /// texture, such as a rotated sprite in a packed atlas.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderGeometryRaw")]
+    pub fn copy_quad(
+        &mut self,
+        texture: &Texture,
+        dst_corners: [FPoint; 4],
+        src_uvs: [FPoint; 4],
+        tint: pixels::Color,
+    ) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
+        let xy: [f32; 8] = [
+            dst_corners[0].x,
+            dst_corners[0].y,
+            dst_corners[1].x,
+            dst_corners[1].y,
+            dst_corners[2].x,
+            dst_corners[2].y,
+            dst_corners[3].x,
+            dst_corners[3].y,
+        ];
+        let uv: [f32; 8] = [
+            src_uvs[0].x,
+            src_uvs[0].y,
+            src_uvs[1].x,
+            src_uvs[1].y,
+            src_uvs[2].x,
+            src_uvs[2].y,
+            src_uvs[3].x,
+            src_uvs[3].y,
+        ];
+        let color = sys::pixels::SDL_FColor {
+            r: tint.r as f32 / 255.0,
+            g: tint.g as f32 / 255.0,
+            b: tint.b as f32 / 255.0,
+            a: tint.a as f32 / 255.0,
+        };
+        let colors = [color; 4];
+        let indices: [c_int; 6] = [0, 1, 2, 0, 2, 3];
+        let float_pair = (2 * mem::size_of::<f32>()) as c_int;
+
+        let ret = unsafe {
+            sys::render::SDL_RenderGeometryRaw(
+                self.context.checked_raw(),
+                texture.raw,
+                xy.as_ptr(),
+                float_pair,
+                colors.as_ptr(),
+                mem::size_of::<sys::pixels::SDL_FColor>() as c_int,
+                uv.as_ptr(),
+                float_pair,
+                4,
+                indices.as_ptr() as *const libc::c_void,
+                indices.len() as c_int,
+                mem::size_of::<c_int>() as c_int,
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Renders a list of triangles, optionally using a texture and indices into the vertex
+    /// array.
+    ///
+    /// Without `indices`, every three consecutive vertices form a triangle. With `indices`,
+    /// every three consecutive indices do. Color and alpha modulation is done per vertex;
+    /// the texture's own color and alpha modulation is ignored.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderGeometry")]
+    pub fn render_geometry(
+        &mut self,
+        vertices: &[Vertex],
+        texture: Option<&Texture>,
+        indices: Option<&[i32]>,
+    ) -> Result<(), Error> {
+        if !self.draw_enabled {
+            return Ok(());
+        }
+        let vertices: Vec<sys::render::SDL_Vertex> = vertices.iter().map(Vertex::to_ll).collect();
+        let (indices_ptr, num_indices) = match indices {
+            Some(indices) => (indices.as_ptr(), indices.len()),
+            None => (ptr::null(), 0),
+        };
+
+        let ret = unsafe {
+            sys::render::SDL_RenderGeometry(
+                self.context.checked_raw(),
+                texture.map_or(ptr::null_mut(), |t| t.raw),
+                vertices.as_ptr(),
+                vertices.len() as c_int,
+                indices_ptr,
+                num_indices as c_int,
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Copies many regions of a texture at once, batched into a single geometry call.
+    ///
+    /// This draws the same thing as calling [`Canvas::copy`] for each instance, in order, but
+    /// with a single call into SDL, which makes a big difference for thousands of sprites. The
+    /// texture's color and alpha modulation are applied like they are by `copy`.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure), or if the provided texture
+    /// does not belong to the renderer.
+    #[doc(alias = "SDL_RenderGeometry")]
+    pub fn copy_batch(
+        &mut self,
+        texture: &Texture,
+        instances: &[SpriteInstance],
+    ) -> Result<(), Error> {
+        if !self.draw_enabled || instances.is_empty() || self.is_transparent_copy(texture) {
+            return Ok(());
+        }
+        let (width, height) = texture.size();
+        let (r, g, b) = texture.color_mod_float();
+        let color = pixels::FColor::RGBA(r, g, b, texture.alpha_mod_float());
+
+        let mut vertices = Vec::with_capacity(instances.len() * 4);
+        let mut indices = Vec::with_capacity(instances.len() * 6);
+        for instance in instances {
+            let (src, dst) = (instance.src, instance.dst);
+            let (u0, v0) = (src.x / width, src.y / height);
+            let (u1, v1) = ((src.x + src.w) / width, (src.y + src.h) / height);
+            let base = vertices.len() as i32;
+            vertices.extend_from_slice(&[
+                Vertex::new(FPoint::new(dst.x, dst.y), color, FPoint::new(u0, v0)),
+                Vertex::new(
+                    FPoint::new(dst.x + dst.w, dst.y),
+                    color,
+                    FPoint::new(u1, v0),
+                ),
+                Vertex::new(
+                    FPoint::new(dst.x + dst.w, dst.y + dst.h),
+                    color,
+                    FPoint::new(u1, v1),
+                ),
+                Vertex::new(
+                    FPoint::new(dst.x, dst.y + dst.h),
+                    color,
+                    FPoint::new(u0, v1),
+                ),
+            ]);
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+        self.render_geometry(&vertices, Some(texture), Some(&indices))
+    }
+
+    /// Draws tiles from a tileset texture, batched into a single geometry call.
+    ///
+    /// The tileset is laid out as a grid of `tile_size` tiles, `tiles_per_row` tiles wide, and
+    /// each item of `tiles` is a `(tile_index, dst)` pair where `tile_index` counts tiles left
+    /// to right, then top to bottom. `tiles` is consumed lazily, so it can come straight from a
+    /// culled spatial query without collecting it first. The texture's color and alpha
+    /// modulation are applied like they are by [`Canvas::copy`].
+    ///
+    /// Errors if `tiles_per_row` is zero, or if drawing fails for any reason.
+    pub fn draw_tiles<I>(
+        &mut self,
+        texture: &Texture,
+        tiles_per_row: u32,
+        tile_size: (u32, u32),
+        tiles: I,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = (u32, FRect)>,
+    {
+        if tiles_per_row == 0 {
+            return Err(Error("tiles_per_row must be greater than zero".to_owned()));
+        }
+        if !self.draw_enabled || self.is_transparent_copy(texture) {
+            return Ok(());
+        }
+        let tile_w = tile_size.0 as f32 / texture.width() as f32;
+        let tile_h = tile_size.1 as f32 / texture.height() as f32;
+        let (r, g, b) = texture.color_mod_float();
+        let color = pixels::FColor::RGBA(r, g, b, texture.alpha_mod_float());
+
+        let tiles = tiles.into_iter();
+        let (lower, _) = tiles.size_hint();
+        let mut vertices = Vec::with_capacity(lower * 4);
+        let mut indices = Vec::with_capacity(lower * 6);
+        for (tile_index, dst) in tiles {
+            let u = (tile_index % tiles_per_row) as f32 * tile_w;
+            let v = (tile_index / tiles_per_row) as f32 * tile_h;
+            let base = vertices.len() as i32;
+            vertices.extend_from_slice(&[
+                Vertex::new(FPoint::new(dst.x, dst.y), color, FPoint::new(u, v)),
+                Vertex::new(
+                    FPoint::new(dst.x + dst.w, dst.y),
+                    color,
+                    FPoint::new(u + tile_w, v),
+                ),
+                Vertex::new(
+                    FPoint::new(dst.x + dst.w, dst.y + dst.h),
+                    color,
+                    FPoint::new(u + tile_w, v + tile_h),
+                ),
+                Vertex::new(
+                    FPoint::new(dst.x, dst.y + dst.h),
+                    color,
+                    FPoint::new(u, v + tile_h),
+                ),
+            ]);
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+        if vertices.is_empty() {
+            return Ok(());
+        }
+        self.render_geometry(&vertices, Some(texture), Some(&indices))
+    }
+
+    /// Reads pixels from the current rendering target, converted to `format`.
+    ///
+    /// If `rect` is `None`, the entire rendering target is read.
     /// # Remarks
     /// WARNING: This is a very slow operation, and should not be used frequently.
     #[doc(alias = "SDL_RenderReadPixels")]
     pub fn read_pixels<R: Into<Option<Rect>>>(
         &self,
         rect: R,
-        // format: pixels::PixelFormat,
+        format: PixelFormat,
     ) -> Result<Surface, Error> {
+        let surface = self.read_pixels_default(rect)?;
+        if surface.pixel_format_enum() == format {
+            Ok(surface)
+        } else {
+            surface.convert_format(format)
+        }
+    }
+
+    /// Reads pixels from the current rendering target, in whatever pixel format the renderer
+    /// produces.
+    ///
+    /// If `rect` is `None`, the entire rendering target is read.
+    /// # Remarks
+    /// WARNING: This is a very slow operation, and should not be used frequently.
+    #[doc(alias = "SDL_RenderReadPixels")]
+    pub fn read_pixels_default<R: Into<Option<Rect>>>(&self, rect: R) -> Result<Surface, Error> {
         unsafe {
             let rect = rect.into();
             let (actual_rect, _w, _h) = match rect {
@@ -1606,7 +4985,8 @@ impl<T: RenderTarget> Canvas<T> {
                 }
             };
 
-            let surface_ptr = sys::render::SDL_RenderReadPixels(self.context.raw, actual_rect);
+            let surface_ptr =
+                sys::render::SDL_RenderReadPixels(self.context.checked_raw(), actual_rect);
             if surface_ptr.is_null() {
                 return Err(get_error());
             }
@@ -1616,6 +4996,65 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Reads the color of a single pixel of the current rendering target, e.g. for a color
+    /// picker.
+    ///
+    /// Only that pixel is read back, so this is much cheaper than reading the whole target with
+    /// [`Canvas::read_pixels`], but it still waits for rendering to finish.
+    ///
+    /// Errors if the pixel is outside of the rendering target, or if reading failed.
+    #[doc(alias = "SDL_RenderReadPixels")]
+    pub fn read_pixel(&self, x: i32, y: i32) -> Result<pixels::Color, Error> {
+        // Backends disagree on out-of-range rects: some fail, some clamp, some read nothing.
+        let (width, height) = match self.render_target_dimensions() {
+            Some(size) => size,
+            None => self.output_size()?,
+        };
+        if x < 0 || y < 0 || x as u32 >= width || y as u32 >= height {
+            return Err(Error(format!(
+                "pixel ({}, {}) is outside of the {}x{} rendering target",
+                x, y, width, height
+            )));
+        }
+        let surface = self.read_pixels_default(Rect::new(x, y, 1, 1))?;
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        let ret = unsafe {
+            sys::surface::SDL_ReadSurfacePixel(surface.raw(), 0, 0, &mut r, &mut g, &mut b, &mut a)
+        };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(pixels::Color::RGBA(r, g, b, a))
+        }
+    }
+
+    /// Saves the entire current rendering target to a file, e.g. to debug rendering or in
+    /// tests.
+    ///
+    /// The file is a BMP, or a PNG if the `image` feature is enabled and `path` ends with
+    /// `.png`. Without the `image` feature, a `.png` path is an error.
+    /// # Remarks
+    /// WARNING: This reads back the pixels like [`Canvas::read_pixels`], which is a very slow
+    /// operation, and should not be used frequently.
+    pub fn save_screenshot<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let is_png = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+        if is_png && cfg!(not(feature = "image")) {
+            return Err(Error(
+                "saving a PNG screenshot requires the `image` feature".to_owned(),
+            ));
+        }
+        let surface = self.read_pixels_default(None)?;
+        #[cfg(feature = "image")]
+        if is_png {
+            use crate::image::SaveSurface;
+            return surface.save(path);
+        }
+        surface.save_bmp(path)
+    }
+
     /// Creates a texture for a rendering context.
     ///
     /// If format is `None`, the format will be the one the parent Window or Surface uses.
@@ -1645,8 +5084,8 @@ impl<T: RenderTarget> Canvas<T> {
         F: Into<Option<PixelFormat>>,
     {
         use self::TextureValueError::*;
-        let format: PixelFormat = format.into().unwrap_or(self.default_pixel_format);
-        let result = ll_create_texture(self.context.raw(), format, access, width, height)?;
+        let format: PixelFormat = format.into().unwrap_or(self.default_pixel_format());
+        let result = ll_create_texture(self.context.checked_raw(), format, access, width, height)?;
         if result.is_null() {
             Err(SdlError(get_error()))
         } else {
@@ -1728,7 +5167,10 @@ impl<T: RenderTarget> Canvas<T> {
     ) -> Result<Texture, TextureValueError> {
         use self::TextureValueError::*;
         let result = unsafe {
-            sys::render::SDL_CreateTextureFromSurface(self.context.raw, surface.as_ref().raw())
+            sys::render::SDL_CreateTextureFromSurface(
+                self.context.checked_raw(),
+                surface.as_ref().raw(),
+            )
         };
         if result.is_null() {
             Err(SdlError(get_error()))
@@ -1747,14 +5189,167 @@ impl<T: RenderTarget> Canvas<T> {
         Texture { raw }
     }
 
+    /// Forces the rendering commands SDL has batched so far to be sent to the GPU.
+    ///
+    /// Call this before making calls to the underlying graphics API directly, e.g. with the
+    /// device returned by [`Canvas::vulkan_device`], so they happen after the drawing done
+    /// through the `Canvas`. SDL flushes on its own before presenting and when reading pixels.
+    ///
+    /// Errors if SDL fails to flush, e.g. because the render device was lost.
+    #[doc(alias = "SDL_FlushRenderer")]
+    pub fn flush(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::render::SDL_FlushRenderer(self.context.checked_raw()) };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[deprecated(note = "use `flush`, which is safe and returns an error instead of panicking")]
     #[doc(alias = "SDL_FlushRenderer")]
     pub unsafe fn flush_renderer(&self) {
-        let ret = sys::render::SDL_FlushRenderer(self.context.raw);
+        let ret = sys::render::SDL_FlushRenderer(self.context.checked_raw());
 
         if !ret {
             panic!("Error flushing renderer: {}", get_error())
         }
     }
+
+    /// Gets the `ID3D11Device` used by the renderer, if it is a Direct3D 11 renderer.
+    ///
+    /// The device is not retained: it is only valid for as long as the renderer, and the caller
+    /// must `AddRef` it to keep it any longer.
+    ///
+    /// Returns `None` for any other renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D11_DEVICE_POINTER")]
+    pub fn d3d11_device(&self) -> Option<*mut libc::c_void> {
+        self.context
+            .get_pointer_property(sys::render::SDL_PROP_RENDERER_D3D11_DEVICE_POINTER)
+    }
+
+    /// Gets the `ID3D12Device` used by the renderer, if it is a Direct3D 12 renderer.
+    ///
+    /// The device is not retained: it is only valid for as long as the renderer, and the caller
+    /// must `AddRef` it to keep it any longer.
+    ///
+    /// Returns `None` for any other renderer.
+    #[cfg(feature = "d3d12")]
+    #[doc(alias = "SDL_PROP_RENDERER_D3D12_DEVICE_POINTER")]
+    pub fn d3d12_device(&self) -> Option<*mut libc::c_void> {
+        self.context
+            .get_pointer_property(sys::render::SDL_PROP_RENDERER_D3D12_DEVICE_POINTER)
+    }
+
+    /// Gets the `ID3D12CommandQueue` used by the renderer, if it is a Direct3D 12 renderer.
+    ///
+    /// Work submitted to this queue is ordered with SDL's own rendering. The queue is not
+    /// retained, see [`Canvas::d3d12_device`].
+    ///
+    /// Returns `None` for any other renderer.
+    #[cfg(feature = "d3d12")]
+    #[doc(alias = "SDL_PROP_RENDERER_D3D12_COMMAND_QUEUE_POINTER")]
+    pub fn d3d12_command_queue(&self) -> Option<*mut libc::c_void> {
+        self.context
+            .get_pointer_property(sys::render::SDL_PROP_RENDERER_D3D12_COMMAND_QUEUE_POINTER)
+    }
+
+    /// Gets the `VkInstance` used by the renderer, if it is a Vulkan renderer.
+    ///
+    /// Returns `None` for any other renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_INSTANCE_POINTER")]
+    pub fn vulkan_instance(&self) -> Option<*mut libc::c_void> {
+        self.context
+            .get_pointer_property(sys::render::SDL_PROP_RENDERER_VULKAN_INSTANCE_POINTER)
+    }
+
+    /// Gets the `VkPhysicalDevice` used by the renderer, if it is a Vulkan renderer.
+    ///
+    /// Returns `None` for any other renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_PHYSICAL_DEVICE_POINTER")]
+    pub fn vulkan_physical_device(&self) -> Option<*mut libc::c_void> {
+        self.context
+            .get_pointer_property(sys::render::SDL_PROP_RENDERER_VULKAN_PHYSICAL_DEVICE_POINTER)
+    }
+
+    /// Gets the `VkDevice` used by the renderer, if it is a Vulkan renderer.
+    ///
+    /// Returns `None` for any other renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_DEVICE_POINTER")]
+    pub fn vulkan_device(&self) -> Option<*mut libc::c_void> {
+        self.context
+            .get_pointer_property(sys::render::SDL_PROP_RENDERER_VULKAN_DEVICE_POINTER)
+    }
+
+    /// Gets the queue family index used for rendering, if this is a Vulkan renderer.
+    ///
+    /// Returns `None` for any other renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_GRAPHICS_QUEUE_FAMILY_INDEX_NUMBER")]
+    pub fn graphics_queue_family(&self) -> Option<u32> {
+        self.context
+            .get_number_property(
+                sys::render::SDL_PROP_RENDERER_VULKAN_GRAPHICS_QUEUE_FAMILY_INDEX_NUMBER,
+            )
+            .map(|index| index as u32)
+    }
+
+    /// Gets the queue family index used for presentation, if this is a Vulkan renderer.
+    ///
+    /// Returns `None` for any other renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_PRESENT_QUEUE_FAMILY_INDEX_NUMBER")]
+    pub fn present_queue_family(&self) -> Option<u32> {
+        self.context
+            .get_number_property(
+                sys::render::SDL_PROP_RENDERER_VULKAN_PRESENT_QUEUE_FAMILY_INDEX_NUMBER,
+            )
+            .map(|index| index as u32)
+    }
+
+    /// Gets the `SDL_GPUDevice` used by the renderer, if it is the `"gpu"` renderer, to mix
+    /// SDL_GPU passes with the 2D API.
+    ///
+    /// The device is owned by SDL and is only valid for as long as the renderer. It must not be
+    /// destroyed by the caller.
+    ///
+    /// Returns `None` for any other renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_GPU_DEVICE_POINTER")]
+    pub fn gpu_device(&self) -> Option<*mut libc::c_void> {
+        self.context
+            .get_pointer_property(sys::render::SDL_PROP_RENDERER_GPU_DEVICE_POINTER)
+    }
+
+    /// Gets the `CAMetalLayer` associated with the renderer, if it is a Metal renderer.
+    ///
+    /// Returns `None` for any other renderer.
+    #[cfg(feature = "metal")]
+    #[doc(alias = "SDL_GetRenderMetalLayer")]
+    pub fn metal_layer(&self) -> Option<*mut libc::c_void> {
+        let layer = unsafe { sys::render::SDL_GetRenderMetalLayer(self.context.checked_raw()) };
+        if layer.is_null() {
+            None
+        } else {
+            Some(layer)
+        }
+    }
+
+    /// Gets the Metal command encoder for the current frame, if this is a Metal renderer.
+    ///
+    /// The encoder is only valid between `clear` and `present`: SDL creates a new one every
+    /// frame, so don't keep the pointer around. SDL also doesn't know about any state changes
+    /// made through it, so restore anything you change before drawing with the `Canvas` again.
+    ///
+    /// Returns `None` for any other renderer.
+    #[cfg(feature = "metal")]
+    #[doc(alias = "SDL_GetRenderMetalCommandEncoder")]
+    pub fn metal_command_encoder(&self) -> Option<*mut libc::c_void> {
+        let encoder =
+            unsafe { sys::render::SDL_GetRenderMetalCommandEncoder(self.context.checked_raw()) };
+        if encoder.is_null() {
+            None
+        } else {
+            Some(encoder)
+        }
+    }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -1817,6 +5412,20 @@ impl Drop for Texture<'_> {
     }
 }
 
+#[cfg(not(feature = "unsafe_textures"))]
+impl fmt::Debug for Texture<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        InternalTexture { raw: self.raw }.fmt_debug(f)
+    }
+}
+
+#[cfg(feature = "unsafe_textures")]
+impl fmt::Debug for Texture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        InternalTexture { raw: self.raw }.fmt_debug(f)
+    }
+}
+
 #[cfg(feature = "unsafe_textures")]
 impl Texture {
     /// Destroy the Texture and its representation
@@ -1843,11 +5452,18 @@ impl Texture {
 #[derive(Debug, Clone)]
 pub enum UpdateTextureError {
     PitchOverflows(usize),
+    InvalidPixelDataLength {
+        length: usize,
+        expected: usize,
+    },
     PitchMustBeMultipleOfTwoForFormat(usize, PixelFormat),
     XMustBeMultipleOfTwoForFormat(i32, PixelFormat),
     YMustBeMultipleOfTwoForFormat(i32, PixelFormat),
     WidthMustBeMultipleOfTwoForFormat(u32, PixelFormat),
     HeightMustBeMultipleOfTwoForFormat(u32, PixelFormat),
+    SurfaceTooSmall(Rect),
+    /// The texture's format doesn't have 4 bytes per pixel, as `update_rgba` requires.
+    UnsupportedFormat(PixelFormat),
     SdlError(Error),
 }
 
@@ -1857,6 +5473,13 @@ impl fmt::Display for UpdateTextureError {
 
         match *self {
             PitchOverflows(value) => write!(f, "Pitch overflows ({})", value),
+            InvalidPixelDataLength { length, expected } => {
+                write!(
+                    f,
+                    "Pixel data is wrong length ({}, should be {})",
+                    length, expected
+                )
+            }
             PitchMustBeMultipleOfTwoForFormat(value, format) => {
                 write!(
                     f,
@@ -1892,6 +5515,17 @@ impl fmt::Display for UpdateTextureError {
                     format, value
                 )
             }
+            SurfaceTooSmall(rect) => write!(
+                f,
+                "Surface is smaller than the updated area ({}x{})",
+                rect.width(),
+                rect.height()
+            ),
+            UnsupportedFormat(format) => write!(
+                f,
+                "Pixel format '{:?}' doesn't have 4 bytes per pixel",
+                format
+            ),
             SdlError(ref e) => write!(f, "SDL error: {}", e),
         }
     }
@@ -1903,11 +5537,14 @@ impl error::Error for UpdateTextureError {
 
         match *self {
             PitchOverflows(_) => "pitch overflow",
+            InvalidPixelDataLength { .. } => "invalid pixel data length",
             PitchMustBeMultipleOfTwoForFormat(..) => "pitch must be multiple of two",
             XMustBeMultipleOfTwoForFormat(..) => "x must be multiple of two",
             YMustBeMultipleOfTwoForFormat(..) => "y must be multiple of two",
             WidthMustBeMultipleOfTwoForFormat(..) => "width must be multiple of two",
             HeightMustBeMultipleOfTwoForFormat(..) => "height must be multiple of two",
+            SurfaceTooSmall(_) => "surface is smaller than the updated area",
+            UnsupportedFormat(_) => "pixel format doesn't have 4 bytes per pixel",
             SdlError(ref e) => &e.0,
         }
     }
@@ -2041,6 +5678,127 @@ impl InternalTexture {
         }
     }
 
+    pub fn get_colorspace(&self) -> pixels::Colorspace {
+        let colorspace = unsafe {
+            sys::properties::SDL_GetNumberProperty(
+                self.get_properties(),
+                sys::render::SDL_PROP_TEXTURE_COLORSPACE_NUMBER,
+                sys::pixels::SDL_COLORSPACE_UNKNOWN.0 as i64,
+            )
+        };
+        sys::pixels::SDL_Colorspace(colorspace as u32).into()
+    }
+
+    pub fn get_estimated_size_bytes(&self) -> usize {
+        let format = self.get_format();
+        let pitch = format.pitch_for_width(self.get_width());
+        format.byte_size_from_pitch_and_height(pitch, self.get_height() as usize)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("Texture");
+        debug.field("raw", &self.raw);
+        // The properties can't be queried without a texture.
+        if !self.raw.is_null() {
+            debug
+                .field("format", &self.get_format())
+                .field("access", &self.get_access())
+                .field("width", &self.get_width())
+                .field("height", &self.get_height());
+        }
+        debug.finish()
+    }
+
+    #[doc(alias = "SDL_GetRendererFromTexture")]
+    fn check_renderer<T>(&self, context: &RendererContext<T>) -> Result<(), Error> {
+        if unsafe { sys::render::SDL_GetRendererFromTexture(self.raw) } != context.raw {
+            Err(Error(
+                "the texture does not belong to this renderer".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reads back pixels of this render target texture by temporarily making it the target of
+    /// `context`, then restores the previous target.
+    #[doc(alias = "SDL_RenderReadPixels")]
+    fn read_pixels<T>(
+        &self,
+        context: &RendererContext<T>,
+        rect: Option<Rect>,
+    ) -> Result<Surface<'static>, Error> {
+        check_render_target(self.raw).map_err(|e| Error(e.to_string()))?;
+        self.check_renderer(context)?;
+
+        let previous_target = unsafe { context.get_raw_target() };
+        unsafe { context.set_raw_target(self.raw)? };
+        let rect_ptr = rect.as_ref().map_or(ptr::null(), |rect| rect.raw());
+        let surface_ptr = unsafe { sys::render::SDL_RenderReadPixels(context.raw, rect_ptr) };
+        let result = if surface_ptr.is_null() {
+            Err(get_error())
+        } else {
+            Ok(unsafe { Surface::from_ll(surface_ptr) })
+        };
+        let restored = unsafe { context.set_raw_target(previous_target) };
+        restored.and(result)
+    }
+
+    /// Copies this texture's pixels as-is into `target`, a render target texture of the same
+    /// size created by the same renderer.
+    ///
+    /// The blend mode and color/alpha modulation of this texture are disabled for the copy and
+    /// restored afterwards, as is the renderer's current target.
+    #[doc(alias = "SDL_RenderTexture")]
+    fn copy_into<T>(
+        &self,
+        context: &RendererContext<T>,
+        target: *mut sys::render::SDL_Texture,
+    ) -> Result<(), Error> {
+        self.check_renderer(context)?;
+
+        let mut blend: SDL_BlendMode = 0;
+        let (mut r, mut g, mut b, mut a) = (0.0, 0.0, 0.0, 0.0);
+        let saved = unsafe {
+            sys::render::SDL_GetTextureBlendMode(self.raw, &mut blend)
+                && sys::render::SDL_GetTextureColorModFloat(self.raw, &mut r, &mut g, &mut b)
+                && sys::render::SDL_GetTextureAlphaModFloat(self.raw, &mut a)
+        };
+        if !saved {
+            return Err(get_error());
+        }
+
+        let previous_target = unsafe { context.get_raw_target() };
+        unsafe { context.set_raw_target(target)? };
+        let ret = unsafe {
+            sys::render::SDL_SetTextureBlendMode(self.raw, sys::blendmode::SDL_BLENDMODE_NONE)
+                && sys::render::SDL_SetTextureColorModFloat(self.raw, 1.0, 1.0, 1.0)
+                && sys::render::SDL_SetTextureAlphaModFloat(self.raw, 1.0)
+                && sys::render::SDL_RenderTexture(context.raw, self.raw, ptr::null(), ptr::null())
+        };
+        let result = if ret { Ok(()) } else { Err(get_error()) };
+
+        unsafe {
+            sys::render::SDL_SetTextureBlendMode(self.raw, blend);
+            sys::render::SDL_SetTextureColorModFloat(self.raw, r, g, b);
+            sys::render::SDL_SetTextureAlphaModFloat(self.raw, a);
+        }
+        let restored = unsafe { context.set_raw_target(previous_target) };
+        result.and(restored)
+    }
+
+    #[doc(alias = "SDL_GetTextureSize")]
+    pub fn get_size(&self) -> (f32, f32) {
+        let mut width = 0.0;
+        let mut height = 0.0;
+        let ret = unsafe { sys::render::SDL_GetTextureSize(self.raw, &mut width, &mut height) };
+        // Should only fail on an invalid texture
+        if !ret {
+            panic!("{}", get_error())
+        }
+        (width, height)
+    }
+
     #[doc(alias = "SDL_SetTextureColorMod")]
     pub fn set_color_mod(&mut self, red: u8, green: u8, blue: u8) {
         let ret = unsafe { sys::render::SDL_SetTextureColorMod(self.raw, red, green, blue) };
@@ -2085,15 +5843,71 @@ impl InternalTexture {
         }
     }
 
+    #[doc(alias = "SDL_SetTextureColorModFloat")]
+    pub fn set_color_mod_float(&mut self, red: f32, green: f32, blue: f32) {
+        let ret = unsafe { sys::render::SDL_SetTextureColorModFloat(self.raw, red, green, blue) };
+
+        if !ret {
+            panic!("Error setting color mod: {}", get_error())
+        }
+    }
+
+    #[doc(alias = "SDL_GetTextureColorModFloat")]
+    pub fn color_mod_float(&self) -> (f32, f32, f32) {
+        let (mut r, mut g, mut b) = (0.0, 0.0, 0.0);
+        let ret =
+            unsafe { sys::render::SDL_GetTextureColorModFloat(self.raw, &mut r, &mut g, &mut b) };
+
+        // Should only fail on an invalid texture
+        if !ret {
+            panic!("{}", get_error())
+        } else {
+            (r, g, b)
+        }
+    }
+
+    #[doc(alias = "SDL_SetTextureAlphaModFloat")]
+    pub fn set_alpha_mod_float(&mut self, alpha: f32) {
+        let ret = unsafe { sys::render::SDL_SetTextureAlphaModFloat(self.raw, alpha) };
+
+        if !ret {
+            panic!("Error setting alpha mod: {}", get_error())
+        }
+    }
+
+    #[doc(alias = "SDL_GetTextureAlphaModFloat")]
+    pub fn alpha_mod_float(&self) -> f32 {
+        let mut alpha = 0.0;
+        let ret = unsafe { sys::render::SDL_GetTextureAlphaModFloat(self.raw, &mut alpha) };
+
+        // Should only fail on an invalid texture
+        if !ret {
+            panic!("{}", get_error())
+        } else {
+            alpha
+        }
+    }
+
     #[doc(alias = "SDL_SetTextureBlendMode")]
     pub fn set_blend_mode(&mut self, blend: BlendMode) {
-        let ret = unsafe { sys::render::SDL_SetTextureBlendMode(self.raw, blend as u32) };
+        let ret = unsafe { sys::render::SDL_SetTextureBlendMode(self.raw, blend.to_ll()) };
 
         if !ret {
             panic!("Error setting blend: {}", get_error())
         }
     }
 
+    #[doc(alias = "SDL_SetTextureBlendMode")]
+    pub fn set_custom_blend_mode(&mut self, blend: CustomBlendMode) -> Result<(), Error> {
+        let ret = unsafe { sys::render::SDL_SetTextureBlendMode(self.raw, blend.to_ll()) };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
     #[doc(alias = "SDL_GetTextureBlendMode")]
     pub fn blend_mode(&self) -> BlendMode {
         let mut blend: MaybeUninit<SDL_BlendMode> = mem::MaybeUninit::uninit();
@@ -2104,7 +5918,7 @@ impl InternalTexture {
             panic!("{}", get_error())
         } else {
             let blend = unsafe { blend.assume_init() };
-            BlendMode::try_from(blend).unwrap()
+            BlendMode::from(blend)
         }
     }
 
@@ -2188,11 +6002,103 @@ impl InternalTexture {
             )
         };
 
-        if !result {
-            Err(SdlError(get_error()))
-        } else {
-            Ok(())
+        if !result {
+            Err(SdlError(get_error()))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[doc(alias = "SDL_BLENDMODE_BLEND_PREMULTIPLIED")]
+    pub fn update_premultiplied<R>(
+        &mut self,
+        rect: R,
+        pixel_data: &[u8],
+        pitch: usize,
+    ) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        let ret = unsafe {
+            sys::render::SDL_SetTextureBlendMode(
+                self.raw,
+                sys::blendmode::SDL_BLENDMODE_BLEND_PREMULTIPLIED,
+            )
+        };
+        if !ret {
+            return Err(UpdateTextureError::SdlError(get_error()));
+        }
+        self.update(rect, pixel_data, pitch)
+    }
+
+    pub fn update_rgba<R>(&mut self, rect: R, pixel_data: &[u8]) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        // SDL copies `width * bytes_per_pixel` bytes per row, so the pitch below is only
+        // right for 4-byte formats.
+        use sys::pixels::SDL_PixelFormat as F;
+        let format = self.get_format();
+        if !matches!(
+            F::from(format),
+            F::XRGB8888
+                | F::RGBX8888
+                | F::XBGR8888
+                | F::BGRX8888
+                | F::ARGB8888
+                | F::RGBA8888
+                | F::ABGR8888
+                | F::BGRA8888
+                | F::ARGB2101010
+        ) {
+            return Err(UpdateTextureError::UnsupportedFormat(format));
+        }
+
+        let rect = rect.into();
+        let (width, height) = match rect {
+            Some(ref r) => (r.width() as usize, r.height() as usize),
+            None => (self.get_width() as usize, self.get_height() as usize),
+        };
+        let pitch = width * 4;
+
+        // SDL reads `pitch * height` bytes, so refuse anything else instead of
+        // letting it read past the end of the slice.
+        let expected = pitch * height;
+        if pixel_data.len() != expected {
+            return Err(UpdateTextureError::InvalidPixelDataLength {
+                length: pixel_data.len(),
+                expected,
+            });
+        }
+
+        self.update(rect, pixel_data, pitch)
+    }
+
+    pub fn update_from_surface<R>(
+        &mut self,
+        rect: R,
+        surface: &SurfaceRef,
+    ) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        let rect = rect.into();
+        let format = self.get_format();
+        if surface.pixel_format() != format {
+            let converted = surface
+                .convert_format(format)
+                .map_err(UpdateTextureError::SdlError)?;
+            return self.update_from_surface(rect, &converted);
+        }
+
+        // The pixels are read from the top-left corner of the surface.
+        let area = rect.unwrap_or_else(|| Rect::new(0, 0, self.get_width(), self.get_height()));
+        if surface.width() < area.width() || surface.height() < area.height() {
+            return Err(UpdateTextureError::SurfaceTooSmall(area));
         }
+
+        let pitch = surface.pitch() as usize;
+        surface.with_lock(|pixels| self.update(rect, pixels, pitch))
     }
 
     #[doc(alias = "SDL_UpdateYUVTexture")]
@@ -2369,16 +6275,96 @@ impl InternalTexture {
         }
     }
 
-    // not really sure about this!
-    unsafe fn get_gl_texture_id(&self) -> Sint64 {
-        let props_id = unsafe { SDL_GetTextureProperties(self.raw) };
-        unsafe {
-            sys::properties::SDL_GetNumberProperty(
-                props_id,
-                sys::render::SDL_PROP_TEXTURE_OPENGL_TEXTURE_NUMBER,
-                0,
-            )
+    pub fn put_pixel(&mut self, x: u32, y: u32, color: pixels::Color) -> Result<(), Error> {
+        if x >= self.get_width() || y >= self.get_height() {
+            return Err(Error(format!("pixel ({}, {}) is out of bounds", x, y)));
+        }
+        let format = self.get_format();
+        match unsafe { format.raw() } {
+            sys::pixels::SDL_PixelFormat::YV12
+            | sys::pixels::SDL_PixelFormat::IYUV
+            | sys::pixels::SDL_PixelFormat::NV12
+            | sys::pixels::SDL_PixelFormat::NV21
+            | sys::pixels::SDL_PixelFormat::YUY2
+            | sys::pixels::SDL_PixelFormat::UYVY
+            | sys::pixels::SDL_PixelFormat::YVYU
+            | sys::pixels::SDL_PixelFormat::INDEX8 => {
+                return Err(Error(format!(
+                    "put_pixel does not support format {:?}",
+                    format
+                )))
+            }
+            _ => {}
+        }
+        let bpp = format.byte_size_per_pixel();
+        let bytes = format.pack(color).to_ne_bytes();
+        // The packed value lives in the low bits of the u32
+        let bytes = if cfg!(target_endian = "big") {
+            &bytes[4 - bpp..]
+        } else {
+            &bytes[..bpp]
+        };
+
+        let rect = Rect::new(x as i32, y as i32, 1, 1);
+        self.with_lock(rect, |buffer, _| buffer[..bpp].copy_from_slice(bytes))
+    }
+
+    pub fn with_lock_surface<F, R, R2>(&mut self, rect: R2, func: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut SurfaceRef) -> R,
+        R2: Into<Option<Rect>>,
+    {
+        // Unlocks the texture when dropped, so the lock is released even if `func` panics.
+        struct LockGuard(*mut sys::render::SDL_Texture);
+
+        impl Drop for LockGuard {
+            #[doc(alias = "SDL_UnlockTexture")]
+            fn drop(&mut self) {
+                unsafe { sys::render::SDL_UnlockTexture(self.0) }
+            }
+        }
+
+        let rect = rect.into();
+        let rect_raw_ptr = match rect {
+            Some(ref rect) => rect.raw(),
+            None => ptr::null(),
+        };
+
+        let mut surface = ptr::null_mut();
+        let ret =
+            unsafe { sys::render::SDL_LockTextureToSurface(self.raw, rect_raw_ptr, &mut surface) };
+        if !ret {
+            return Err(get_error());
         }
+
+        let _guard = LockGuard(self.raw);
+        Ok(func(unsafe { SurfaceRef::from_ll_mut(surface) }))
+    }
+
+    /// Reads the first of `names` that is set in the texture's properties.
+    fn get_number_property(&self, names: &[*const libc::c_char]) -> Option<i64> {
+        let props_id = unsafe { SDL_GetTextureProperties(self.raw) };
+        names.iter().find_map(|&name| unsafe {
+            if sys::properties::SDL_HasProperty(props_id, name) {
+                Some(sys::properties::SDL_GetNumberProperty(props_id, name, 0))
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn gl_texture_id(&self) -> Option<i64> {
+        self.get_number_property(&[
+            sys::render::SDL_PROP_TEXTURE_OPENGL_TEXTURE_NUMBER,
+            sys::render::SDL_PROP_TEXTURE_OPENGLES2_TEXTURE_NUMBER,
+        ])
+    }
+
+    pub fn gl_texture_target(&self) -> Option<i64> {
+        self.get_number_property(&[
+            sys::render::SDL_PROP_TEXTURE_OPENGL_TEXTURE_TARGET_NUMBER,
+            sys::render::SDL_PROP_TEXTURE_OPENGLES2_TEXTURE_TARGET_NUMBER,
+        ])
     }
 
     // removed:
@@ -2426,6 +6412,31 @@ impl InternalTexture {
 
 #[cfg(not(feature = "unsafe_textures"))]
 impl Texture<'_> {
+    /// Creates an independent copy of this texture, e.g. to keep a snapshot before applying an
+    /// effect to it.
+    ///
+    /// The copy is a [`TextureAccess::Target`] texture of the same size and format, drawn by
+    /// temporarily making it the render target of `creator`'s renderer. The blend mode and
+    /// color/alpha modulation of the copy are left at their defaults.
+    ///
+    /// Errors if the texture does not belong to `creator`'s renderer, or if the renderer can't
+    /// render to a texture of this format.
+    pub fn duplicate<'c, T>(&self, creator: &'c TextureCreator<T>) -> Result<Texture<'c>, Error> {
+        let internal = InternalTexture { raw: self.raw };
+        let copy = creator
+            .create_texture_target(
+                internal.get_format(),
+                internal.get_width(),
+                internal.get_height(),
+            )
+            .map_err(|e| match e {
+                TextureValueError::SdlError(e) => e,
+                e => Error(e.to_string()),
+            })?;
+        internal.copy_into(&*creator.context, copy.raw)?;
+        Ok(copy)
+    }
+
     /// Gets the texture's internal properties.
     #[inline]
     pub fn query(&self) -> TextureQuery {
@@ -2450,6 +6461,13 @@ impl Texture<'_> {
         InternalTexture { raw: self.raw }.get_access()
     }
 
+    /// Checks whether the texture can be used as a render target, i.e. whether it was created
+    /// with `TextureAccess::Target`.
+    #[inline]
+    pub fn is_render_target(&self) -> bool {
+        self.access() == TextureAccess::Target
+    }
+
     /// Get the width of the texture.
     #[inline]
     pub fn width(&self) -> u32 {
@@ -2462,6 +6480,51 @@ impl Texture<'_> {
         InternalTexture { raw: self.raw }.get_height()
     }
 
+    /// Get the colorspace of the texture.
+    #[inline]
+    #[doc(alias = "SDL_PROP_TEXTURE_COLORSPACE_NUMBER")]
+    pub fn colorspace(&self) -> pixels::Colorspace {
+        InternalTexture { raw: self.raw }.get_colorspace()
+    }
+
+    /// Estimates how much memory the texture's pixels take, from its size and format, e.g. to
+    /// track a texture budget by summing it over all live textures.
+    ///
+    /// This is only an estimate: it ignores mip levels, row padding, and anything else the
+    /// driver allocates.
+    #[inline]
+    pub fn estimated_size_bytes(&self) -> usize {
+        InternalTexture { raw: self.raw }.get_estimated_size_bytes()
+    }
+
+    /// Gets the OpenGL texture name (`GLuint`) of the texture, to bind it directly with OpenGL.
+    ///
+    /// This replaces `SDL_GL_BindTexture` from SDL2. Returns `None` if the renderer is not
+    /// backed by OpenGL or OpenGL ES 2.
+    #[inline]
+    #[doc(alias = "SDL_PROP_TEXTURE_OPENGL_TEXTURE_NUMBER")]
+    pub fn gl_texture_id(&self) -> Option<i64> {
+        InternalTexture { raw: self.raw }.gl_texture_id()
+    }
+
+    /// Gets the OpenGL texture target (`GLenum`) of the texture, e.g. `GL_TEXTURE_2D`.
+    ///
+    /// Returns `None` if the renderer is not backed by OpenGL or OpenGL ES 2.
+    #[inline]
+    #[doc(alias = "SDL_PROP_TEXTURE_OPENGL_TEXTURE_TARGET_NUMBER")]
+    pub fn gl_texture_target(&self) -> Option<i64> {
+        InternalTexture { raw: self.raw }.gl_texture_target()
+    }
+
+    /// Get the size of the texture, with floating point precision.
+    ///
+    /// This is the authoritative size for layout math, e.g. when rendering the texture into
+    /// an `FRect`; `width()` and `height()` are its integer counterparts.
+    #[inline]
+    pub fn size(&self) -> (f32, f32) {
+        InternalTexture { raw: self.raw }.get_size()
+    }
+
     /// Sets an additional color value multiplied into render copy operations.
     #[inline]
     pub fn set_color_mod(&mut self, red: u8, green: u8, blue: u8) {
@@ -2486,12 +6549,48 @@ impl Texture<'_> {
         InternalTexture { raw: self.raw }.alpha_mod()
     }
 
+    /// Sets an additional color value multiplied into render copy operations, with floating
+    /// point precision. Values above 1.0 brighten the texture, which is useful for HDR.
+    #[inline]
+    pub fn set_color_mod_float(&mut self, red: f32, green: f32, blue: f32) {
+        InternalTexture { raw: self.raw }.set_color_mod_float(red, green, blue)
+    }
+
+    /// Gets the additional color value multiplied into render copy operations, with floating
+    /// point precision.
+    #[inline]
+    pub fn color_mod_float(&self) -> (f32, f32, f32) {
+        InternalTexture { raw: self.raw }.color_mod_float()
+    }
+
+    /// Sets an additional alpha value multiplied into render copy operations, with floating
+    /// point precision.
+    #[inline]
+    pub fn set_alpha_mod_float(&mut self, alpha: f32) {
+        InternalTexture { raw: self.raw }.set_alpha_mod_float(alpha)
+    }
+
+    /// Gets the additional alpha value multiplied into render copy operations, with floating
+    /// point precision.
+    #[inline]
+    pub fn alpha_mod_float(&self) -> f32 {
+        InternalTexture { raw: self.raw }.alpha_mod_float()
+    }
+
     /// Sets the blend mode used for drawing operations (Fill and Line).
     #[inline]
     pub fn set_blend_mode(&mut self, blend: BlendMode) {
         InternalTexture { raw: self.raw }.set_blend_mode(blend)
     }
 
+    /// Sets a custom blend mode for texture copy operations.
+    ///
+    /// Errors if the renderer does not support the given blend mode.
+    #[inline]
+    pub fn set_custom_blend_mode(&mut self, blend: CustomBlendMode) -> Result<(), Error> {
+        InternalTexture { raw: self.raw }.set_custom_blend_mode(blend)
+    }
+
     /// Gets the blend mode used for texture copy operations.
     #[inline]
     pub fn blend_mode(&self) -> BlendMode {
@@ -2529,6 +6628,85 @@ impl Texture<'_> {
         InternalTexture { raw: self.raw }.update(rect, pixel_data, pitch)
     }
 
+    /// Reads the pixels of this texture back from the GPU, e.g. to save it or to keep a copy
+    /// for undo.
+    ///
+    /// This temporarily makes the texture the render target of `canvas`, then restores the
+    /// previous target. If `rect` is `None`, the entire texture is read.
+    ///
+    /// Errors if the texture was not created with `TextureAccess::Target`, since SDL can only
+    /// read back render targets, if it does not belong to the renderer of `canvas`, or if
+    /// reading failed.
+    /// # Remarks
+    /// WARNING: This is a very slow operation, and should not be used frequently.
+    #[inline]
+    #[doc(alias = "SDL_RenderReadPixels")]
+    pub fn read_pixels<T: RenderTarget>(
+        &self,
+        canvas: &Canvas<T>,
+        rect: Option<Rect>,
+    ) -> Result<Surface<'static>, Error> {
+        InternalTexture { raw: self.raw }.read_pixels(&*canvas.context, rect)
+    }
+
+    /// Updates the given texture rectangle with pixel data whose color channels are already
+    /// multiplied by alpha, and switches the texture to premultiplied alpha blending
+    /// (`SDL_BLENDMODE_BLEND_PREMULTIPLIED`).
+    ///
+    /// Premultiplied pixels avoid the dark fringes that straight alpha leaves around
+    /// anti-aliased edges. See [`pixels::premultiply_alpha`] to convert straight-alpha pixels.
+    ///
+    /// * If `rect` is `None`, the entire texture is updated.
+    #[inline]
+    pub fn update_premultiplied<R>(
+        &mut self,
+        rect: R,
+        pixel_data: &[u8],
+        pitch: usize,
+    ) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        InternalTexture { raw: self.raw }.update_premultiplied(rect, pixel_data, pitch)
+    }
+
+    /// Updates the given texture rectangle with tightly packed RGBA8888 pixel data.
+    ///
+    /// The pitch is derived as `width * 4`, so `pixel_data` must contain exactly
+    /// `width * height * 4` bytes for the rectangle, otherwise
+    /// `UpdateTextureError::InvalidPixelDataLength` is returned. The texture must use a packed
+    /// 32-bit format such as `SDL_PIXELFORMAT_RGBA8888`, otherwise
+    /// `UpdateTextureError::UnsupportedFormat` is returned.
+    ///
+    /// * If `rect` is `None`, the entire texture is updated.
+    #[inline]
+    pub fn update_rgba<R>(&mut self, rect: R, pixel_data: &[u8]) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        InternalTexture { raw: self.raw }.update_rgba(rect, pixel_data)
+    }
+
+    /// Updates the given texture rectangle with the pixels of a surface, e.g. to refresh a
+    /// texture of rendered text without creating a new one.
+    ///
+    /// The pixels are read from the top-left corner of the surface, which must be at least as
+    /// large as the rectangle. If the surface's pixel format differs from the texture's, a
+    /// converted copy of the surface is uploaded instead.
+    ///
+    /// * If `rect` is `None`, the entire texture is updated.
+    #[inline]
+    pub fn update_from_surface<R>(
+        &mut self,
+        rect: R,
+        surface: &SurfaceRef,
+    ) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        InternalTexture { raw: self.raw }.update_from_surface(rect, surface)
+    }
+
     /// Updates a rectangle within a planar YV12 or IYUV texture with new pixel data.
     #[inline]
     pub fn update_yuv<R>(
@@ -2567,6 +6745,36 @@ impl Texture<'_> {
         InternalTexture { raw: self.raw }.with_lock(rect, func)
     }
 
+    /// Locks the texture for **write-only** pixel access, exposing it as a surface.
+    /// The texture must have been created with streaming access.
+    ///
+    /// `F` is passed a surface that can be drawn or blitted into with the usual `Surface`
+    /// helpers. The surface is only valid while the texture is locked, so it is only reachable
+    /// inside `F`; the texture is unlocked afterwards, even if `F` panics.
+    /// # Remarks
+    /// As with [`with_lock`](Self::with_lock), the surface doesn't necessarily contain the old
+    /// texture data.
+    #[doc(alias = "SDL_LockTextureToSurface")]
+    pub fn with_lock_surface<F, R, R2>(&mut self, rect: R2, func: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut SurfaceRef) -> R,
+        R2: Into<Option<Rect>>,
+    {
+        InternalTexture { raw: self.raw }.with_lock_surface(rect, func)
+    }
+
+    /// Writes a single pixel, packing `color` into the texture's pixel format.
+    /// The texture must have been created with streaming access.
+    ///
+    /// This locks the texture for every call, so prefer [`with_lock`](Self::with_lock) and
+    /// [`PixelFormat::pack`] when writing many pixels.
+    ///
+    /// Errors if the pixel is out of bounds, if the texture is YUV or indexed, or if the
+    /// texture could not be locked.
+    pub fn put_pixel(&mut self, x: u32, y: u32, color: pixels::Color) -> Result<(), Error> {
+        InternalTexture { raw: self.raw }.put_pixel(x, y, color)
+    }
+
     // /// Binds an OpenGL/ES/ES2 texture to the current
     // /// context for use with when rendering OpenGL primitives directly.
     // #[inline]
@@ -2661,6 +6869,35 @@ impl Texture<'_> {
 
 #[cfg(feature = "unsafe_textures")]
 impl Texture {
+    /// Creates an independent copy of this texture, e.g. to keep a snapshot before applying an
+    /// effect to it.
+    ///
+    /// The copy is a [`TextureAccess::Target`] texture of the same size and format, drawn by
+    /// temporarily making it the render target of `creator`'s renderer. The blend mode and
+    /// color/alpha modulation of the copy are left at their defaults.
+    ///
+    /// Errors if the texture does not belong to `creator`'s renderer, or if the renderer can't
+    /// render to a texture of this format.
+    pub fn duplicate<T>(&self, creator: &TextureCreator<T>) -> Result<Texture, Error> {
+        let internal = InternalTexture { raw: self.raw };
+        let copy = creator
+            .create_texture_target(
+                internal.get_format(),
+                internal.get_width(),
+                internal.get_height(),
+            )
+            .map_err(|e| match e {
+                TextureValueError::SdlError(e) => e,
+                e => Error(e.to_string()),
+            })?;
+        if let Err(e) = internal.copy_into(&*creator.context, copy.raw) {
+            // Textures aren't destroyed on drop with `unsafe_textures`.
+            unsafe { copy.destroy() };
+            return Err(e);
+        }
+        Ok(copy)
+    }
+
     /// Gets the texture's internal properties.
     #[inline]
     pub fn query(&self) -> TextureQuery {
@@ -2685,6 +6922,13 @@ impl Texture {
         InternalTexture { raw: self.raw }.get_access()
     }
 
+    /// Checks whether the texture can be used as a render target, i.e. whether it was created
+    /// with `TextureAccess::Target`.
+    #[inline]
+    pub fn is_render_target(&self) -> bool {
+        self.access() == TextureAccess::Target
+    }
+
     /// Get the width of the texture.
     #[inline]
     pub fn width(&self) -> u32 {
@@ -2697,6 +6941,51 @@ impl Texture {
         InternalTexture { raw: self.raw }.get_height()
     }
 
+    /// Get the colorspace of the texture.
+    #[inline]
+    #[doc(alias = "SDL_PROP_TEXTURE_COLORSPACE_NUMBER")]
+    pub fn colorspace(&self) -> pixels::Colorspace {
+        InternalTexture { raw: self.raw }.get_colorspace()
+    }
+
+    /// Estimates how much memory the texture's pixels take, from its size and format, e.g. to
+    /// track a texture budget by summing it over all live textures.
+    ///
+    /// This is only an estimate: it ignores mip levels, row padding, and anything else the
+    /// driver allocates.
+    #[inline]
+    pub fn estimated_size_bytes(&self) -> usize {
+        InternalTexture { raw: self.raw }.get_estimated_size_bytes()
+    }
+
+    /// Gets the OpenGL texture name (`GLuint`) of the texture, to bind it directly with OpenGL.
+    ///
+    /// This replaces `SDL_GL_BindTexture` from SDL2. Returns `None` if the renderer is not
+    /// backed by OpenGL or OpenGL ES 2.
+    #[inline]
+    #[doc(alias = "SDL_PROP_TEXTURE_OPENGL_TEXTURE_NUMBER")]
+    pub fn gl_texture_id(&self) -> Option<i64> {
+        InternalTexture { raw: self.raw }.gl_texture_id()
+    }
+
+    /// Gets the OpenGL texture target (`GLenum`) of the texture, e.g. `GL_TEXTURE_2D`.
+    ///
+    /// Returns `None` if the renderer is not backed by OpenGL or OpenGL ES 2.
+    #[inline]
+    #[doc(alias = "SDL_PROP_TEXTURE_OPENGL_TEXTURE_TARGET_NUMBER")]
+    pub fn gl_texture_target(&self) -> Option<i64> {
+        InternalTexture { raw: self.raw }.gl_texture_target()
+    }
+
+    /// Get the size of the texture, with floating point precision.
+    ///
+    /// This is the authoritative size for layout math, e.g. when rendering the texture into
+    /// an `FRect`; `width()` and `height()` are its integer counterparts.
+    #[inline]
+    pub fn size(&self) -> (f32, f32) {
+        InternalTexture { raw: self.raw }.get_size()
+    }
+
     /// Sets an additional color value multiplied into render copy operations.
     #[inline]
     pub fn set_color_mod(&mut self, red: u8, green: u8, blue: u8) {
@@ -2721,35 +7010,162 @@ impl Texture {
         InternalTexture { raw: self.raw }.alpha_mod()
     }
 
+    /// Sets an additional color value multiplied into render copy operations, with floating
+    /// point precision. Values above 1.0 brighten the texture, which is useful for HDR.
+    #[inline]
+    pub fn set_color_mod_float(&mut self, red: f32, green: f32, blue: f32) {
+        InternalTexture { raw: self.raw }.set_color_mod_float(red, green, blue)
+    }
+
+    /// Gets the additional color value multiplied into render copy operations, with floating
+    /// point precision.
+    #[inline]
+    pub fn color_mod_float(&self) -> (f32, f32, f32) {
+        InternalTexture { raw: self.raw }.color_mod_float()
+    }
+
+    /// Sets an additional alpha value multiplied into render copy operations, with floating
+    /// point precision.
+    #[inline]
+    pub fn set_alpha_mod_float(&mut self, alpha: f32) {
+        InternalTexture { raw: self.raw }.set_alpha_mod_float(alpha)
+    }
+
+    /// Gets the additional alpha value multiplied into render copy operations, with floating
+    /// point precision.
+    #[inline]
+    pub fn alpha_mod_float(&self) -> f32 {
+        InternalTexture { raw: self.raw }.alpha_mod_float()
+    }
+
     /// Sets the blend mode used for drawing operations (Fill and Line).
     #[inline]
-    pub fn set_blend_mode(&mut self, blend: BlendMode) {
-        InternalTexture { raw: self.raw }.set_blend_mode(blend)
+    pub fn set_blend_mode(&mut self, blend: BlendMode) {
+        InternalTexture { raw: self.raw }.set_blend_mode(blend)
+    }
+
+    /// Sets a custom blend mode for texture copy operations.
+    ///
+    /// Errors if the renderer does not support the given blend mode.
+    #[inline]
+    pub fn set_custom_blend_mode(&mut self, blend: CustomBlendMode) -> Result<(), Error> {
+        InternalTexture { raw: self.raw }.set_custom_blend_mode(blend)
+    }
+
+    /// Gets the blend mode used for texture copy operations.
+    #[inline]
+    pub fn blend_mode(&self) -> BlendMode {
+        InternalTexture { raw: self.raw }.blend_mode()
+    }
+
+    /// Sets the scale mode for use when rendered.
+    #[inline]
+    pub fn set_scale_mode(&mut self, scale: ScaleMode) {
+        InternalTexture { raw: self.raw }.set_scale_mode(scale)
+    }
+
+    /// Gets the scale mode for use when rendered.
+    #[inline]
+    pub fn scale_mode(&self) -> ScaleMode {
+        InternalTexture { raw: self.raw }.scale_mode()
+    }
+
+    /// Updates the given texture rectangle with new pixel data.
+    ///
+    /// `pitch` is the number of bytes in a row of pixel data, including padding
+    /// between lines
+    ///
+    /// * If `rect` is `None`, the entire texture is updated.
+    #[inline]
+    pub fn update<R>(
+        &mut self,
+        rect: R,
+        pixel_data: &[u8],
+        pitch: usize,
+    ) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        InternalTexture { raw: self.raw }.update(rect, pixel_data, pitch)
+    }
+
+    /// Reads the pixels of this texture back from the GPU, e.g. to save it or to keep a copy
+    /// for undo.
+    ///
+    /// This temporarily makes the texture the render target of `canvas`, then restores the
+    /// previous target. If `rect` is `None`, the entire texture is read.
+    ///
+    /// Errors if the texture was not created with `TextureAccess::Target`, since SDL can only
+    /// read back render targets, if it does not belong to the renderer of `canvas`, or if
+    /// reading failed.
+    /// # Remarks
+    /// WARNING: This is a very slow operation, and should not be used frequently.
+    #[inline]
+    #[doc(alias = "SDL_RenderReadPixels")]
+    pub fn read_pixels<T: RenderTarget>(
+        &self,
+        canvas: &Canvas<T>,
+        rect: Option<Rect>,
+    ) -> Result<Surface<'static>, Error> {
+        InternalTexture { raw: self.raw }.read_pixels(&*canvas.context, rect)
+    }
+
+    /// Updates the given texture rectangle with pixel data whose color channels are already
+    /// multiplied by alpha, and switches the texture to premultiplied alpha blending
+    /// (`SDL_BLENDMODE_BLEND_PREMULTIPLIED`).
+    ///
+    /// Premultiplied pixels avoid the dark fringes that straight alpha leaves around
+    /// anti-aliased edges. See [`pixels::premultiply_alpha`] to convert straight-alpha pixels.
+    ///
+    /// * If `rect` is `None`, the entire texture is updated.
+    #[inline]
+    pub fn update_premultiplied<R>(
+        &mut self,
+        rect: R,
+        pixel_data: &[u8],
+        pitch: usize,
+    ) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        InternalTexture { raw: self.raw }.update_premultiplied(rect, pixel_data, pitch)
     }
 
-    /// Gets the blend mode used for texture copy operations.
+    /// Updates the given texture rectangle with tightly packed RGBA8888 pixel data.
+    ///
+    /// The pitch is derived as `width * 4`, so `pixel_data` must contain exactly
+    /// `width * height * 4` bytes for the rectangle, otherwise
+    /// `UpdateTextureError::InvalidPixelDataLength` is returned. The texture must use a packed
+    /// 32-bit format such as `SDL_PIXELFORMAT_RGBA8888`, otherwise
+    /// `UpdateTextureError::UnsupportedFormat` is returned.
+    ///
+    /// * If `rect` is `None`, the entire texture is updated.
     #[inline]
-    pub fn blend_mode(&self) -> BlendMode {
-        InternalTexture { raw: self.raw }.blend_mode()
+    pub fn update_rgba<R>(&mut self, rect: R, pixel_data: &[u8]) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        InternalTexture { raw: self.raw }.update_rgba(rect, pixel_data)
     }
 
-    /// Updates the given texture rectangle with new pixel data.
+    /// Updates the given texture rectangle with the pixels of a surface, e.g. to refresh a
+    /// texture of rendered text without creating a new one.
     ///
-    /// `pitch` is the number of bytes in a row of pixel data, including padding
-    /// between lines
+    /// The pixels are read from the top-left corner of the surface, which must be at least as
+    /// large as the rectangle. If the surface's pixel format differs from the texture's, a
+    /// converted copy of the surface is uploaded instead.
     ///
     /// * If `rect` is `None`, the entire texture is updated.
     #[inline]
-    pub fn update<R>(
+    pub fn update_from_surface<R>(
         &mut self,
         rect: R,
-        pixel_data: &[u8],
-        pitch: usize,
+        surface: &SurfaceRef,
     ) -> Result<(), UpdateTextureError>
     where
         R: Into<Option<Rect>>,
     {
-        InternalTexture { raw: self.raw }.update(rect, pixel_data, pitch)
+        InternalTexture { raw: self.raw }.update_from_surface(rect, surface)
     }
 
     /// Updates a rectangle within a planar YV12 or IYUV texture with new pixel data.
@@ -2790,6 +7206,36 @@ impl Texture {
         InternalTexture { raw: self.raw }.with_lock(rect, func)
     }
 
+    /// Locks the texture for **write-only** pixel access, exposing it as a surface.
+    /// The texture must have been created with streaming access.
+    ///
+    /// `F` is passed a surface that can be drawn or blitted into with the usual `Surface`
+    /// helpers. The surface is only valid while the texture is locked, so it is only reachable
+    /// inside `F`; the texture is unlocked afterwards, even if `F` panics.
+    /// # Remarks
+    /// As with [`with_lock`](Self::with_lock), the surface doesn't necessarily contain the old
+    /// texture data.
+    #[doc(alias = "SDL_LockTextureToSurface")]
+    pub fn with_lock_surface<F, R, R2>(&mut self, rect: R2, func: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut SurfaceRef) -> R,
+        R2: Into<Option<Rect>>,
+    {
+        InternalTexture { raw: self.raw }.with_lock_surface(rect, func)
+    }
+
+    /// Writes a single pixel, packing `color` into the texture's pixel format.
+    /// The texture must have been created with streaming access.
+    ///
+    /// This locks the texture for every call, so prefer [`with_lock`](Self::with_lock) and
+    /// [`PixelFormat::pack`] when writing many pixels.
+    ///
+    /// Errors if the pixel is out of bounds, if the texture is YUV or indexed, or if the
+    /// texture could not be locked.
+    pub fn put_pixel(&mut self, x: u32, y: u32, color: pixels::Color) -> Result<(), Error> {
+        InternalTexture { raw: self.raw }.put_pixel(x, y, color)
+    }
+
     // these are not supplied by SDL anymore
     // not sure if we should support them since we'd need to pull in OpenGL
     // /// Binds an OpenGL/ES/ES2 texture to the current
@@ -2820,6 +7266,455 @@ impl Texture {
     }
 }
 
+/// A pair of streaming textures that are written to alternately.
+///
+/// SDL's 2D render API has no asynchronous texture upload: `SDL_UpdateTexture` and
+/// `SDL_LockTexture` may stall if the texture is still in use by the GPU for a previous frame.
+/// `StreamingTexture` avoids this by always uploading into the texture that was *not* drawn
+/// last, then swapping, so per-frame uploads (e.g. video playback) never touch the texture that
+/// is still in flight.
+///
+/// Draw with [`StreamingTexture::current`], which always returns the most recently updated
+/// texture.
+#[cfg(not(feature = "unsafe_textures"))]
+pub struct StreamingTexture<'r> {
+    textures: [Texture<'r>; 2],
+    front: usize,
+}
+
+/// A pair of streaming textures that are written to alternately. See the
+/// non-`unsafe_textures` variant for why.
+#[cfg(feature = "unsafe_textures")]
+pub struct StreamingTexture {
+    textures: [Texture; 2],
+    front: usize,
+}
+
+#[cfg(not(feature = "unsafe_textures"))]
+impl<'r> StreamingTexture<'r> {
+    /// Creates two streaming textures of the given format and size.
+    ///
+    /// If format is `None`, the creator's default pixel format is used.
+    pub fn new<T, F>(
+        texture_creator: &'r TextureCreator<T>,
+        format: F,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, TextureValueError>
+    where
+        F: Into<Option<PixelFormat>>,
+    {
+        let format = format.into();
+        Ok(StreamingTexture {
+            textures: [
+                texture_creator.create_texture_streaming(format, width, height)?,
+                texture_creator.create_texture_streaming(format, width, height)?,
+            ],
+            front: 0,
+        })
+    }
+
+    /// Gets the texture that was updated last. This is the one to draw.
+    #[inline]
+    pub fn current(&self) -> &Texture<'r> {
+        &self.textures[self.front]
+    }
+
+    /// Gets both textures mutably, e.g. to set their blend or scale mode.
+    #[inline]
+    pub fn textures_mut(&mut self) -> &mut [Texture<'r>; 2] {
+        &mut self.textures
+    }
+
+    /// Uploads new pixel data into the back texture, then makes it the current one.
+    ///
+    /// See [`Texture::update`] for the meaning of the arguments.
+    pub fn update<R>(
+        &mut self,
+        rect: R,
+        pixel_data: &[u8],
+        pitch: usize,
+    ) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        let back = 1 - self.front;
+        self.textures[back].update(rect, pixel_data, pitch)?;
+        self.front = back;
+        Ok(())
+    }
+
+    /// Locks the back texture for write-only access, then makes it the current one.
+    ///
+    /// `f` is passed the whole texture buffer and its pitch, like with [`Texture::with_lock`].
+    /// The buffer doesn't necessarily contain the previous frame, so every pixel should be
+    /// written.
+    pub fn with_next_frame<F, R>(&mut self, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut [u8], usize) -> R,
+    {
+        let back = 1 - self.front;
+        let result = self.textures[back].with_lock(None, f)?;
+        self.front = back;
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "unsafe_textures")]
+impl StreamingTexture {
+    /// Creates two streaming textures of the given format and size.
+    ///
+    /// If format is `None`, the creator's default pixel format is used.
+    pub fn new<T, F>(
+        texture_creator: &TextureCreator<T>,
+        format: F,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, TextureValueError>
+    where
+        F: Into<Option<PixelFormat>>,
+    {
+        let format = format.into();
+        Ok(StreamingTexture {
+            textures: [
+                texture_creator.create_texture_streaming(format, width, height)?,
+                texture_creator.create_texture_streaming(format, width, height)?,
+            ],
+            front: 0,
+        })
+    }
+
+    /// Gets the texture that was updated last. This is the one to draw.
+    #[inline]
+    pub fn current(&self) -> &Texture {
+        &self.textures[self.front]
+    }
+
+    /// Gets both textures mutably, e.g. to set their blend or scale mode.
+    #[inline]
+    pub fn textures_mut(&mut self) -> &mut [Texture; 2] {
+        &mut self.textures
+    }
+
+    /// Uploads new pixel data into the back texture, then makes it the current one.
+    ///
+    /// See [`Texture::update`] for the meaning of the arguments.
+    pub fn update<R>(
+        &mut self,
+        rect: R,
+        pixel_data: &[u8],
+        pitch: usize,
+    ) -> Result<(), UpdateTextureError>
+    where
+        R: Into<Option<Rect>>,
+    {
+        let back = 1 - self.front;
+        self.textures[back].update(rect, pixel_data, pitch)?;
+        self.front = back;
+        Ok(())
+    }
+
+    /// Locks the back texture for write-only access, then makes it the current one.
+    ///
+    /// `f` is passed the whole texture buffer and its pitch, like with [`Texture::with_lock`].
+    /// The buffer doesn't necessarily contain the previous frame, so every pixel should be
+    /// written.
+    pub fn with_next_frame<F, R>(&mut self, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut [u8], usize) -> R,
+    {
+        let back = 1 - self.front;
+        let result = self.textures[back].with_lock(None, f)?;
+        self.front = back;
+        Ok(result)
+    }
+
+    /// Destroys both textures.
+    ///
+    /// **Calling this method while no parent is alive is undefined behavior**
+    pub unsafe fn destroy(self) {
+        let [a, b] = self.textures;
+        a.destroy();
+        b.destroy();
+    }
+}
+
+/// A set of regions of a texture, e.g. the frames of a sprite sheet.
+///
+/// Regions are looked up by index, in the order they were added, or by name if they were
+/// added with [`TextureAtlas::add_named`]. The atlas borrows the texture, so it can't outlive
+/// it.
+///
+/// ```rust,no_run
+/// # use sdl3::render::{Canvas, FRect, Texture, TextureAtlas};
+/// # use sdl3::video::Window;
+/// # let mut canvas : Canvas<Window> = unimplemented!();
+/// # let sprite_sheet : Texture = unimplemented!();
+/// // 8 frames of animation, laid out in a single row
+/// let atlas = TextureAtlas::from_grid(&sprite_sheet, 8, 1).unwrap();
+/// atlas
+///     .draw_index(&mut canvas, 3, FRect::new(100.0, 100.0, 32.0, 32.0))
+///     .unwrap();
+/// ```
+pub struct TextureAtlas<'t> {
+    #[cfg(not(feature = "unsafe_textures"))]
+    texture: &'t Texture<'t>,
+    #[cfg(feature = "unsafe_textures")]
+    texture: &'t Texture,
+    regions: Vec<FRect>,
+    names: HashMap<String, usize>,
+}
+
+impl<'t> TextureAtlas<'t> {
+    /// Creates an atlas of `texture` without any region.
+    pub fn new(texture: &'t Texture) -> TextureAtlas<'t> {
+        TextureAtlas {
+            texture,
+            regions: Vec::new(),
+            names: HashMap::new(),
+        }
+    }
+
+    /// Slices `texture` into a uniform grid of `columns` by `rows` regions.
+    ///
+    /// Regions are indexed row by row, starting from the top-left corner.
+    ///
+    /// Errors if `columns` or `rows` is 0.
+    pub fn from_grid(
+        texture: &'t Texture,
+        columns: u32,
+        rows: u32,
+    ) -> Result<TextureAtlas<'t>, Error> {
+        if columns == 0 || rows == 0 {
+            return Err(Error(format!(
+                "invalid atlas grid: {} columns, {} rows",
+                columns, rows
+            )));
+        }
+        let mut atlas = TextureAtlas::new(texture);
+        atlas.regions = grid_regions(texture.width(), texture.height(), columns, rows);
+        Ok(atlas)
+    }
+
+    /// Gets the texture the regions belong to.
+    #[inline]
+    pub fn texture(&self) -> &'t Texture {
+        self.texture
+    }
+
+    /// Adds a region and returns its index.
+    pub fn add(&mut self, rect: FRect) -> usize {
+        self.regions.push(rect);
+        self.regions.len() - 1
+    }
+
+    /// Adds a region that can also be looked up by name, and returns its index.
+    ///
+    /// If the name was already used, it now refers to the new region.
+    pub fn add_named<S: Into<String>>(&mut self, name: S, rect: FRect) -> usize {
+        let index = self.add(rect);
+        self.names.insert(name.into(), index);
+        index
+    }
+
+    /// Gives a name to an existing region, e.g. one created by [`TextureAtlas::from_grid`].
+    ///
+    /// Errors if there is no region at `index`.
+    pub fn set_name<S: Into<String>>(&mut self, name: S, index: usize) -> Result<(), Error> {
+        if index >= self.regions.len() {
+            return Err(Error(format!("no region at index {} in the atlas", index)));
+        }
+        self.names.insert(name.into(), index);
+        Ok(())
+    }
+
+    /// Gets the region at `index`.
+    #[inline]
+    pub fn region(&self, index: usize) -> Option<FRect> {
+        self.regions.get(index).copied()
+    }
+
+    /// Gets the region called `name`.
+    pub fn named_region(&self, name: &str) -> Option<FRect> {
+        self.names.get(name).and_then(|&index| self.region(index))
+    }
+
+    /// Gets the number of regions.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Copies the region called `name` to `dst`, like [`Canvas::copy`].
+    ///
+    /// Errors if there is no such region, or if drawing fails for any reason.
+    pub fn draw<T, R>(&self, canvas: &mut Canvas<T>, name: &str, dst: R) -> Result<(), Error>
+    where
+        T: RenderTarget,
+        R: Into<Option<FRect>>,
+    {
+        let src = self
+            .named_region(name)
+            .ok_or_else(|| Error(format!("no region named {:?} in the atlas", name)))?;
+        canvas.copy(self.texture, src, dst)
+    }
+
+    /// Copies the region at `index` to `dst`, like [`Canvas::copy`].
+    ///
+    /// Errors if there is no such region, or if drawing fails for any reason.
+    pub fn draw_index<T, R>(
+        &self,
+        canvas: &mut Canvas<T>,
+        index: usize,
+        dst: R,
+    ) -> Result<(), Error>
+    where
+        T: RenderTarget,
+        R: Into<Option<FRect>>,
+    {
+        let src = self
+            .region(index)
+            .ok_or_else(|| Error(format!("no region at index {} in the atlas", index)))?;
+        canvas.copy(self.texture, src, dst)
+    }
+}
+
+/// Splits a `width` by `height` area into a grid, row by row.
+fn grid_regions(width: u32, height: u32, columns: u32, rows: u32) -> Vec<FRect> {
+    let cell_w = width as f32 / columns as f32;
+    let cell_h = height as f32 / rows as f32;
+    let mut regions = Vec::with_capacity(columns as usize * rows as usize);
+    for row in 0..rows {
+        for column in 0..columns {
+            regions.push(FRect::new(
+                column as f32 * cell_w,
+                row as f32 * cell_h,
+                cell_w,
+                cell_h,
+            ));
+        }
+    }
+    regions
+}
+
+/// The frames of an animation laid out on a sprite sheet, left to right, then top to bottom.
+///
+/// This only computes source rectangles; it doesn't keep the texture borrowed.
+///
+/// ```rust,no_run
+/// # use std::time::Instant;
+/// # use sdl3::render::{Canvas, FRect, SpriteAnimation, Texture};
+/// # use sdl3::video::Window;
+/// # let mut canvas : Canvas<Window> = unimplemented!();
+/// # let sprite_sheet : Texture = unimplemented!();
+/// # let start = Instant::now();
+/// // 10 frames of 32x32 pixels, played at 12 frames per second
+/// let walk = SpriteAnimation::new(&sprite_sheet, 32, 32, 10, 12.0).unwrap();
+/// let src = walk.frame_at(start.elapsed());
+/// canvas
+///     .copy(&sprite_sheet, src, FRect::new(100.0, 100.0, 64.0, 64.0))
+///     .unwrap();
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SpriteAnimation {
+    frame_width: u32,
+    frame_height: u32,
+    columns: u32,
+    frame_count: u32,
+    fps: f32,
+}
+
+impl SpriteAnimation {
+    /// Describes an animation of `frame_count` frames of `frame_width` by `frame_height`
+    /// pixels each, starting at the top-left corner of `texture`, played at `fps` frames per
+    /// second.
+    ///
+    /// Errors if a frame is empty or wider than the texture, if the frames don't fit in the
+    /// texture, if `frame_count` is 0, or if `fps` is not finite and positive.
+    pub fn new(
+        texture: &Texture,
+        frame_width: u32,
+        frame_height: u32,
+        frame_count: u32,
+        fps: f32,
+    ) -> Result<SpriteAnimation, Error> {
+        if frame_width == 0 || frame_height == 0 || frame_width > texture.width() {
+            return Err(Error(format!(
+                "invalid frame size {}x{} for a {}x{} texture",
+                frame_width,
+                frame_height,
+                texture.width(),
+                texture.height()
+            )));
+        }
+        if !(fps.is_finite() && fps > 0.0) {
+            return Err(Error(format!(
+                "invalid animation speed: {} frames per second",
+                fps
+            )));
+        }
+        let columns = texture.width() / frame_width;
+        let rows = texture.height() / frame_height;
+        if frame_count == 0 || frame_count as u64 > columns as u64 * rows as u64 {
+            return Err(Error(format!(
+                "{} frames of {}x{} don't fit in a {}x{} texture",
+                frame_count,
+                frame_width,
+                frame_height,
+                texture.width(),
+                texture.height()
+            )));
+        }
+        Ok(SpriteAnimation {
+            frame_width,
+            frame_height,
+            columns,
+            frame_count,
+            fps,
+        })
+    }
+
+    /// Gets the number of frames.
+    #[inline]
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+
+    /// Gets the speed of the animation, in frames per second.
+    #[inline]
+    pub fn fps(&self) -> f32 {
+        self.fps
+    }
+
+    /// Gets the source rectangle of frame `index`, wrapping around after the last frame.
+    pub fn frame(&self, index: u32) -> FRect {
+        let index = index % self.frame_count;
+        FRect::new(
+            ((index % self.columns) * self.frame_width) as f32,
+            ((index / self.columns) * self.frame_height) as f32,
+            self.frame_width as f32,
+            self.frame_height as f32,
+        )
+    }
+
+    /// Gets the index of the frame shown after `elapsed` time, looping the animation.
+    pub fn frame_index_at(&self, elapsed: Duration) -> u32 {
+        let frames = (elapsed.as_secs_f64() * self.fps as f64) as u64;
+        (frames % self.frame_count as u64) as u32
+    }
+
+    /// Gets the source rectangle of the frame shown after `elapsed` time, looping the
+    /// animation.
+    pub fn frame_at(&self, elapsed: Duration) -> FRect {
+        self.frame(self.frame_index_at(elapsed))
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct DriverIterator {
     length: i32,
@@ -2855,7 +7750,62 @@ impl Iterator for DriverIterator {
 
 impl ExactSizeIterator for DriverIterator {}
 
+#[derive(Copy, Clone)]
+pub struct DriverNameIterator {
+    length: i32,
+    index: i32,
+}
+
+impl Iterator for DriverNameIterator {
+    type Item = &'static str;
+
+    #[inline]
+    #[doc(alias = "SDL_GetRenderDriver")]
+    fn next(&mut self) -> Option<&'static str> {
+        while self.index < self.length {
+            let result = unsafe { sys::render::SDL_GetRenderDriver(self.index) };
+            self.index += 1;
+
+            // The names are ASCII string literals statically compiled into SDL, so they live
+            // for the whole program. A name that isn't UTF-8 can't be borrowed as a `str`, and
+            // is skipped.
+            let name = unsafe { CStr::from_ptr(result) }.to_str();
+            debug_assert!(name.is_ok(), "render driver name is not valid UTF-8");
+            if let Ok(name) = name {
+                return Some(name);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.length - self.index) as usize))
+    }
+}
+
+/// Gets an iterator of the names of all render drivers compiled into the SDL library, without
+/// allocating.
+///
+/// Prefer this over [`drivers`] when the names only need to be compared or printed:
+///
+/// ```no_run
+/// let has_metal = sdl3::render::render_driver_names().any(|name| name == "metal");
+/// ```
+#[inline]
+#[doc(alias = "SDL_GetNumRenderDrivers")]
+pub fn render_driver_names() -> DriverNameIterator {
+    // See `drivers`.
+    DriverNameIterator {
+        length: unsafe { sys::render::SDL_GetNumRenderDrivers() },
+        index: 0,
+    }
+}
+
 /// Gets an iterator of all render drivers compiled into the SDL2 library.
+///
+/// This allocates a `String` for every name; [`render_driver_names`] yields borrowed names
+/// instead.
 #[inline]
 #[doc(alias = "SDL_GetNumRenderDrivers")]
 pub fn drivers() -> DriverIterator {
@@ -2868,3 +7818,273 @@ pub fn drivers() -> DriverIterator {
         index: 0,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frect_contains_point() {
+        let rect = FRect::new(0.0, 0.0, 10.0, 10.0);
+        assert!(rect.contains_point((0.0, 0.0)));
+        assert!(rect.contains_point((9.5, 9.5)));
+        // right and bottom edges are outside
+        assert!(!rect.contains_point((10.0, 5.0)));
+        assert!(!rect.contains_point((5.0, 10.0)));
+        // zero-area rects contain nothing
+        assert!(!FRect::new(0.0, 0.0, 0.0, 10.0).contains_point((0.0, 0.0)));
+    }
+
+    #[test]
+    fn frect_intersection() {
+        let rect = FRect::new(0.0, 0.0, 10.0, 10.0);
+        assert_eq!(
+            rect.intersection(FRect::new(9.5, 9.5, 10.0, 10.0)),
+            Some(FRect::new(9.5, 9.5, 0.5, 0.5))
+        );
+        assert!(rect.has_intersection(FRect::new(9.5, 9.5, 10.0, 10.0)));
+        // edge
+        assert_eq!(rect.intersection(FRect::new(10.0, 0.0, 10.0, 10.0)), None);
+        assert!(!rect.has_intersection(FRect::new(10.0, 0.0, 10.0, 10.0)));
+        // zero area
+        assert_eq!(rect.intersection(FRect::new(5.0, 5.0, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn frect_union() {
+        assert_eq!(
+            FRect::new(0.0, 0.0, 1.0, 1.0).union(FRect::new(9.0, 9.0, 1.5, 1.5)),
+            FRect::new(0.0, 0.0, 10.5, 10.5)
+        );
+        let rect = FRect::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(rect.union(FRect::new(100.0, 100.0, 0.0, 0.0)), rect);
+        assert_eq!(FRect::new(100.0, 100.0, 0.0, 0.0).union(rect), rect);
+    }
+
+    #[test]
+    fn scale_mode_round_trip() {
+        for mode in [ScaleMode::Nearest, ScaleMode::Linear, ScaleMode::PixelArt] {
+            let raw: sdl3_sys::everything::SDL_ScaleMode = mode.into();
+            assert_eq!(ScaleMode::try_from(raw), Ok(mode));
+        }
+    }
+
+    #[test]
+    fn flip_mode_combinations() {
+        assert_eq!(flip_mode(false, false), SDL_FLIP_NONE);
+        assert_eq!(flip_mode(true, false), SDL_FLIP_HORIZONTAL);
+        assert_eq!(flip_mode(false, true), SDL_FLIP_VERTICAL);
+        assert_eq!(
+            flip_mode(true, true).0,
+            SDL_FLIP_HORIZONTAL.0 | SDL_FLIP_VERTICAL.0
+        );
+        assert_ne!(flip_mode(true, true), SDL_FLIP_HORIZONTAL);
+        assert_ne!(flip_mode(true, true), SDL_FLIP_VERTICAL);
+    }
+
+    #[test]
+    fn circle_segments_bounds() {
+        assert_eq!(circle_segments(0.0), 8);
+        assert_eq!(circle_segments(f32::NAN), 8);
+        assert_eq!(circle_segments(100.0), 40);
+        assert_eq!(circle_segments(1.0e9), 256);
+    }
+
+    #[test]
+    fn circle_points_closed() {
+        let points: Vec<_> = circle_points(FPoint::new(10.0, 20.0), 5.0, 16).collect();
+        assert_eq!(points.len(), 17);
+        assert_eq!(points[0], FPoint::new(15.0, 20.0));
+        let last = points[16];
+        assert!((last.x - 15.0).abs() < 1e-4 && (last.y - 20.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn scale_validation() {
+        assert!(validate_scale(1.0, 2.5).is_ok());
+        assert!(validate_scale(0.0, 1.0).is_err());
+        assert!(validate_scale(1.0, -1.0).is_err());
+        assert!(validate_scale(f32::NAN, 1.0).is_err());
+        assert!(validate_scale(1.0, f32::INFINITY).is_err());
+    }
+
+    #[test]
+    fn atlas_grid_regions() {
+        let regions = grid_regions(64, 32, 4, 2);
+        assert_eq!(regions.len(), 8);
+        assert_eq!(regions[0], FRect::new(0.0, 0.0, 16.0, 16.0));
+        assert_eq!(regions[3], FRect::new(48.0, 0.0, 16.0, 16.0));
+        assert_eq!(regions[4], FRect::new(0.0, 16.0, 16.0, 16.0));
+    }
+
+    #[test]
+    fn wrapped_copy_segments() {
+        // inside the texture
+        assert_eq!(wrap_segments(2.0, 4.0, 10.0), vec![(2.0, 4.0, 0.0)]);
+        // across one edge
+        assert_eq!(
+            wrap_segments(8.0, 5.0, 10.0),
+            vec![(8.0, 2.0, 0.0), (0.0, 3.0, 2.0)]
+        );
+        // negative start, and more than one full repeat
+        assert_eq!(
+            wrap_segments(-3.0, 25.0, 10.0),
+            vec![
+                (7.0, 3.0, 0.0),
+                (0.0, 10.0, 3.0),
+                (0.0, 10.0, 13.0),
+                (0.0, 2.0, 23.0)
+            ]
+        );
+
+        // never loops forever
+        assert!(wrap_segments(0.0, f32::INFINITY, 10.0).is_empty());
+    }
+
+    #[test]
+    fn coordinate_mapper_round_trip() {
+        let mapper = RenderCoordinateMapper {
+            pixel_density: (2.0, 2.0),
+            // 320x180 letterboxed into a 1600x1200 output
+            logical: Some(((320.0, 180.0), FRect::new(0.0, 150.0, 1600.0, 900.0))),
+            scale: (1.0, 1.0),
+            viewport_origin: (0.0, 0.0),
+        };
+        // the top-left corner of the logical area
+        assert_eq!(mapper.to_render((0.0, 75.0)), FPoint::new(0.0, 0.0));
+        assert_eq!(mapper.to_render((400.0, 300.0)), FPoint::new(160.0, 90.0));
+        assert_eq!(mapper.to_window((160.0, 90.0)), FPoint::new(400.0, 300.0));
+
+        let mapper = RenderCoordinateMapper {
+            pixel_density: (1.0, 1.0),
+            logical: None,
+            scale: (2.0, 4.0),
+            viewport_origin: (10.0, 20.0),
+        };
+        assert_eq!(mapper.to_render((100.0, 100.0)), FPoint::new(40.0, 5.0));
+        assert_eq!(mapper.to_window((40.0, 5.0)), FPoint::new(100.0, 100.0));
+    }
+
+    #[test]
+    fn blend_mode_from_ll() {
+        for mode in [
+            BlendMode::None,
+            BlendMode::Blend,
+            BlendMode::Add,
+            BlendMode::Mod,
+            BlendMode::Mul,
+        ] {
+            assert_eq!(BlendMode::from(mode.to_ll()), mode);
+        }
+
+        // Composed by hand, like `SDL_ComposeCustomBlendMode` does.
+        let premultiplied = sys::blendmode::SDL_BlendOperation::ADD.0 as u32
+            | (sys::blendmode::SDL_BlendFactor::ONE.0 as u32) << 4
+            | (sys::blendmode::SDL_BlendFactor::ONE_MINUS_SRC_ALPHA.0 as u32) << 8
+            | (sys::blendmode::SDL_BlendOperation::ADD.0 as u32) << 16
+            | (sys::blendmode::SDL_BlendFactor::ONE.0 as u32) << 20
+            | (sys::blendmode::SDL_BlendFactor::ONE_MINUS_SRC_ALPHA.0 as u32) << 24;
+        assert_eq!(
+            BlendMode::from(premultiplied),
+            BlendMode::Custom(premultiplied)
+        );
+
+        assert_eq!(
+            BlendMode::from(sys::blendmode::SDL_BLENDMODE_INVALID),
+            BlendMode::Invalid
+        );
+        assert_eq!(BlendMode::from(u32::MAX), BlendMode::Invalid);
+        assert_eq!(BlendMode::from(0x0000_0100), BlendMode::Invalid);
+    }
+
+    #[test]
+    fn polygon_fan() {
+        assert!(polygon_fan_indices(2).is_empty());
+        assert_eq!(polygon_fan_indices(3), vec![0, 1, 2]);
+        assert_eq!(polygon_fan_indices(5), vec![0, 1, 2, 0, 2, 3, 0, 3, 4]);
+    }
+
+    #[test]
+    fn sprite_animation_frames() {
+        let animation = SpriteAnimation {
+            frame_width: 32,
+            frame_height: 16,
+            columns: 3,
+            frame_count: 5,
+            fps: 10.0,
+        };
+        assert_eq!(animation.frame(0), FRect::new(0.0, 0.0, 32.0, 16.0));
+        assert_eq!(animation.frame(2), FRect::new(64.0, 0.0, 32.0, 16.0));
+        // wraps to the next row at the edge of the sheet
+        assert_eq!(animation.frame(3), FRect::new(0.0, 16.0, 32.0, 16.0));
+        // and back to the first frame after the last one
+        assert_eq!(animation.frame(5), animation.frame(0));
+
+        assert_eq!(animation.frame_index_at(Duration::from_millis(0)), 0);
+        assert_eq!(animation.frame_index_at(Duration::from_millis(99)), 0);
+        assert_eq!(animation.frame_index_at(Duration::from_millis(450)), 4);
+        assert_eq!(animation.frame_index_at(Duration::from_millis(520)), 0);
+        assert_eq!(
+            animation.frame_at(Duration::from_millis(350)),
+            FRect::new(0.0, 16.0, 32.0, 16.0)
+        );
+    }
+
+    #[test]
+    fn dashed_line_segments() {
+        let start = FPoint::new(0.0, 0.0);
+        assert!(dash_segments(start, start, 4.0, 2.0).unwrap().is_empty());
+
+        let segments = dash_segments(start, FPoint::new(14.0, 0.0), 4.0, 2.0).unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                (FPoint::new(0.0, 0.0), FPoint::new(4.0, 0.0)),
+                (FPoint::new(6.0, 0.0), FPoint::new(10.0, 0.0)),
+                // cut short at the end of the line
+                (FPoint::new(12.0, 0.0), FPoint::new(14.0, 0.0)),
+            ]
+        );
+
+        // ends exactly on a gap
+        let segments = dash_segments(start, FPoint::new(0.0, 12.0), 4.0, 2.0).unwrap();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1], (FPoint::new(0.0, 6.0), FPoint::new(0.0, 10.0)));
+
+        // tiny dashes on a long line would need millions of segments
+        assert!(dash_segments(start, FPoint::new(1000.0, 0.0), 1e-6, 0.0).is_err());
+        assert!(dash_segments(start, FPoint::new(f32::INFINITY, 0.0), 4.0, 2.0).is_err());
+    }
+
+    #[test]
+    fn thick_line_quads_and_caps() {
+        let start = FPoint::new(0.0, 0.0);
+        let end = FPoint::new(10.0, 0.0);
+
+        let (points, indices) = thick_line_geometry(start, end, 2.0, LineCap::Butt);
+        assert_eq!(
+            points,
+            vec![
+                FPoint::new(0.0, 1.0),
+                FPoint::new(0.0, -1.0),
+                FPoint::new(10.0, -1.0),
+                FPoint::new(10.0, 1.0),
+            ]
+        );
+        assert_eq!(indices, vec![0, 1, 2, 0, 2, 3]);
+        assert!(thick_line_geometry(start, start, 2.0, LineCap::Butt)
+            .0
+            .is_empty());
+
+        let (points, indices) = thick_line_geometry(start, end, 2.0, LineCap::Round);
+        assert!(indices.iter().all(|&i| (i as usize) < points.len()));
+        // the caps reach half the width past both ends
+        let reaches = |x: f32| {
+            points
+                .iter()
+                .any(|p| (p.x - x).abs() < 1e-5 && p.y.abs() < 1e-5)
+        };
+        assert!(reaches(-1.0));
+        assert!(reaches(11.0));
+    }
+}
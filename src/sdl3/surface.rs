@@ -13,8 +13,6 @@ use crate::render::{Texture, TextureCreator, TextureValueError};
 use crate::sys;
 use crate::Error;
 use libc::c_int;
-use std::convert::TryFrom;
-use std::mem::transmute;
 use std::ptr;
 use sys::blendmode::SDL_BLENDMODE_NONE;
 use sys::surface::{SDL_ScaleMode, SDL_MUSTLOCK, SDL_SCALEMODE_LINEAR};
@@ -440,9 +438,9 @@ impl SurfaceRef {
     pub fn save_bmp_rw(&self, iostream: &mut IOStream) -> Result<(), Error> {
         let ret = unsafe { sys::surface::SDL_SaveBMP_IO(self.raw(), iostream.raw(), false) };
         if !ret {
-            Ok(())
-        } else {
             Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
@@ -593,7 +591,7 @@ impl SurfaceRef {
     /// The function will fail if the blend mode is not supported by SDL.
     #[doc(alias = "SDL_SetSurfaceBlendMode")]
     pub fn set_blend_mode(&mut self, mode: BlendMode) -> Result<(), Error> {
-        let result = unsafe { sys::surface::SDL_SetSurfaceBlendMode(self.raw(), transmute(mode)) };
+        let result = unsafe { sys::surface::SDL_SetSurfaceBlendMode(self.raw(), mode.to_ll()) };
 
         match result {
             true => Ok(()),
@@ -607,7 +605,7 @@ impl SurfaceRef {
         let result = unsafe { sys::surface::SDL_GetSurfaceBlendMode(self.raw(), &mut mode) };
 
         match result {
-            true => BlendMode::try_from(mode).unwrap(),
+            true => BlendMode::from(mode),
             // Should only fail on a null Surface
             _ => panic!("{}", get_error()),
         }
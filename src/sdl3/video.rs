@@ -3,7 +3,7 @@ use crate::get_error;
 use crate::pixels::PixelFormat;
 use crate::properties::{Properties, PropertiesError};
 use crate::rect::Rect;
-use crate::render::{create_renderer, WindowCanvas};
+use crate::render::{create_renderer, RendererBuilder, WindowCanvas};
 use crate::surface::SurfaceRef;
 use crate::Error;
 use crate::EventPump;
@@ -1604,6 +1604,12 @@ impl Window {
         self.into()
     }
 
+    /// Initializes a new `RendererBuilder`, to create a `WindowCanvas` with custom options
+    /// such as the render driver or vsync.
+    pub fn into_canvas_builder(self) -> RendererBuilder {
+        RendererBuilder::new(self)
+    }
+
     pub fn context(&self) -> Arc<WindowContext> {
         self.context.clone()
     }
@@ -1,5 +1,12 @@
 extern crate sdl3;
-use sdl3::{rect::Rect, render::create_renderer, render::ClippingRect};
+use sdl3::{
+    pixels::{Color, PixelFormat},
+    rect::{Point, Rect},
+    render::create_renderer,
+    render::{Canvas, ClippingRect, FRect, SpriteInstance, StreamingTexture},
+    surface::Surface,
+};
+use std::time::Instant;
 
 #[test]
 fn clipping_rect_intersection() {
@@ -80,6 +87,23 @@ fn clipping_rect_intersect_rect() {
     );
 }
 
+#[test]
+fn clipping_rect_queries() {
+    assert!(ClippingRect::Zero.is_empty());
+    assert!(!ClippingRect::None.is_empty());
+    assert!(!ClippingRect::Some(Rect::new(0, 0, 1, 1)).is_empty());
+
+    assert!(!ClippingRect::Zero.contains_point(Point::new(0, 0)));
+    assert!(ClippingRect::None.contains_point(Point::new(-1000, 1000)));
+    let some = ClippingRect::Some(Rect::new(0, 0, 10, 10));
+    assert!(some.contains_point(Point::new(9, 9)));
+    assert!(!some.contains_point(Point::new(10, 5)));
+
+    assert_eq!(ClippingRect::Zero.area(), Some(0));
+    assert_eq!(ClippingRect::None.area(), None);
+    assert_eq!(some.area(), Some(100));
+}
+
 #[test]
 fn creating_a_named_renderer() {
     // hidden window
@@ -96,3 +120,152 @@ fn creating_a_named_renderer() {
     // the software renderer should always be available
     create_renderer(window, Some(c"software")).unwrap();
 }
+
+#[test]
+fn saving_a_screenshot() {
+    let surface = Surface::new(64, 64, PixelFormat::RGB24).unwrap();
+    let mut canvas = Canvas::from_surface(surface).unwrap();
+    canvas.clear_with(Color::RED).unwrap();
+
+    let path = std::env::temp_dir().join(format!("sdl3-screenshot-{}.bmp", std::process::id()));
+    let result = canvas.save_screenshot(&path);
+    let exists = path.exists();
+    let _ = std::fs::remove_file(&path);
+    assert!(result.is_ok(), "{:?}", result);
+    assert!(exists);
+}
+
+#[test]
+fn viewport_is_in_scaled_coordinates() {
+    let surface = Surface::new(800, 600, PixelFormat::RGB24).unwrap();
+    let mut canvas = Canvas::from_surface(surface).unwrap();
+    assert_eq!(canvas.viewport(), Rect::new(0, 0, 800, 600));
+
+    canvas.set_scale(2.0, 2.0).unwrap();
+    assert_eq!(canvas.viewport(), Rect::new(0, 0, 400, 300));
+}
+
+#[test]
+fn present_at_rejects_unrepresentable_frame_times() {
+    let surface = Surface::new(16, 16, PixelFormat::RGB24).unwrap();
+    let mut canvas = Canvas::from_surface(surface).unwrap();
+    let mut last_present = Instant::now();
+    assert!(canvas.present_at(0.0, &mut last_present).is_err());
+    // one frame would last longer than a `Duration` can hold
+    assert!(canvas.present_at(1e-300, &mut last_present).is_err());
+}
+
+#[test]
+fn from_pixels_rejects_overflowing_buffer_sizes() {
+    let mut pixels = [0u8; 16];
+    assert!(Canvas::from_pixels(1, 2, PixelFormat::RGB24, &mut pixels, usize::MAX).is_err());
+}
+
+#[test]
+fn fill_checkerboard_rejects_tiny_cells() {
+    let surface = Surface::new(16, 16, PixelFormat::RGB24).unwrap();
+    let mut canvas = Canvas::from_surface(surface).unwrap();
+    let rect = FRect::new(0.0, 0.0, 16.0, 16.0);
+    assert!(canvas
+        .fill_checkerboard(rect, 4.0, Color::BLACK, Color::WHITE)
+        .is_ok());
+    assert!(canvas
+        .fill_checkerboard(rect, 1e-30, Color::BLACK, Color::WHITE)
+        .is_err());
+    let huge = FRect::new(0.0, 0.0, 1e30, 1e30);
+    assert!(canvas
+        .fill_checkerboard(huge, 1.0, Color::BLACK, Color::WHITE)
+        .is_err());
+}
+
+#[test]
+fn read_pixel_checks_the_target_bounds() {
+    let surface = Surface::new(16, 8, PixelFormat::RGB24).unwrap();
+    let mut canvas = Canvas::from_surface(surface).unwrap();
+    canvas.set_draw_color(Color::RED);
+    canvas.clear();
+    assert_eq!(canvas.read_pixel(15, 7).unwrap(), Color::RED);
+    assert!(canvas.read_pixel(16, 0).is_err());
+    assert!(canvas.read_pixel(0, 8).is_err());
+    assert!(canvas.read_pixel(-1, 0).is_err());
+}
+
+#[test]
+fn streaming_texture_swaps_after_each_upload() {
+    let surface = Surface::new(4, 4, PixelFormat::RGBA8888).unwrap();
+    let canvas = Canvas::from_surface(surface).unwrap();
+    let texture_creator = canvas.texture_creator();
+    let mut streaming =
+        StreamingTexture::new(&texture_creator, PixelFormat::RGBA8888, 4, 4).unwrap();
+    let first = streaming.current().raw();
+
+    streaming.update(None, &[0xff; 64], 16).unwrap();
+    let second = streaming.current().raw();
+    assert_ne!(first, second);
+
+    streaming
+        .with_next_frame(|pixels, _pitch| pixels.fill(0))
+        .unwrap();
+    assert_eq!(streaming.current().raw(), first);
+
+    // a pitch that doesn't fit in an `i32` is rejected before uploading anything
+    assert!(streaming.update(None, &[0xff; 64], 1 << 31).is_err());
+    assert_eq!(streaming.current().raw(), first);
+}
+
+#[test]
+#[ignore = "benchmark, run with `cargo test --test render -- --ignored --nocapture`"]
+fn copy_batch_versus_copy_loop() {
+    const SPRITES: usize = 10_000;
+    const FRAMES: u32 = 20;
+
+    let window = sdl3::init()
+        .unwrap()
+        .video()
+        .unwrap()
+        .window("copy_batch benchmark", 800, 600)
+        .hidden()
+        .build()
+        .unwrap();
+    let mut canvas = create_renderer(window, Some(c"software")).unwrap();
+    let texture_creator = canvas.texture_creator();
+    let mut texture = texture_creator.create_texture_target(None, 64, 64).unwrap();
+    canvas
+        .with_texture_canvas(&mut texture, |texture_canvas| {
+            texture_canvas.set_draw_color(Color::RED);
+            texture_canvas.clear();
+        })
+        .unwrap();
+
+    let instances: Vec<SpriteInstance> = (0..SPRITES)
+        .map(|i| {
+            let x = (i % 100) as f32 * 8.0;
+            let y = (i / 100) as f32 * 6.0;
+            SpriteInstance::new(
+                FRect::new(16.0, 16.0, 16.0, 16.0),
+                FRect::new(x, y, 16.0, 16.0),
+            )
+        })
+        .collect();
+
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        for instance in &instances {
+            canvas.copy(&texture, instance.src, instance.dst).unwrap();
+        }
+        canvas.present().unwrap();
+    }
+    let looped = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        canvas.copy_batch(&texture, &instances).unwrap();
+        canvas.present().unwrap();
+    }
+    let batched = start.elapsed();
+
+    println!(
+        "{} sprites x {} frames: copy loop {:?}, copy_batch {:?}",
+        SPRITES, FRAMES, looped, batched
+    );
+}